@@ -1,15 +1,25 @@
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
 use std::fs;
-use std::io::{BufReader, BufWriter};
+use std::hash::{Hash, Hasher};
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 use tracing::{info, warn};
 
-const CACHE_VERSION: u32 = 3;
+const CACHE_VERSION: u32 = 5;
 const CACHE_FILENAME: &str = "odoo_ls_cache.bin";
+/// Format of the on-disk `Vec<CachedFile>` symbol cache persisted per module by
+/// [`ModuleCacheManager`]. Bumped whenever the `Cached*` struct shapes change, so stale-schema
+/// caches are discarded wholesale instead of failing to deserialize halfway through.
+const MODULE_CACHE_FORMAT_VERSION: u32 = 1;
+/// Filename of the manifest mapping each module to the combined content hash (and reverse
+/// dependency info) used by [`ModuleCacheManager::validate_all`]/`invalidate_with_dependents`.
+const MODULE_MANIFEST_FILENAME: &str = "modules_manifest.bin";
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedTextRange {
     pub start: u32,
     pub end: u32,
@@ -22,6 +32,7 @@ impl Default for CachedTextRange {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum CachedSymbolType {
     File,
     Class,
@@ -30,6 +41,7 @@ pub enum CachedSymbolType {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedVariable {
     pub name: String,
     pub range: CachedTextRange,
@@ -39,10 +51,19 @@ pub struct CachedVariable {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedArgument {
     pub name: String,
     pub arg_type: String,
     pub has_default: bool,
+    /// Dotted type path / builtin name of the parameter's annotation (e.g. `"int"`,
+    /// `"Outer.Inner"`), best-effort resolved from the symbol tree alone - `None` if the
+    /// parameter wasn't annotated or the annotation's symbol couldn't be found without a live
+    /// session.
+    pub annotation: Option<String>,
+    /// Compact dotted name of the default value's evaluated type (e.g. `"NoneType"`, `"bool"`),
+    /// so a cached default still carries a usable type hint instead of collapsing to `None`.
+    pub default_type: Option<String>,
 }
 
 impl CachedArgument {
@@ -60,6 +81,7 @@ impl CachedArgument {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedFunction {
     pub name: String,
     pub range: CachedTextRange,
@@ -73,6 +95,7 @@ pub struct CachedFunction {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedClass {
     pub name: String,
     pub range: CachedTextRange,
@@ -84,6 +107,7 @@ pub struct CachedClass {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedFile {
     pub name: String,
     pub path: String,
@@ -92,6 +116,7 @@ pub struct CachedFile {
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub enum CachedSymbol {
     Variable(CachedVariable),
     Function(CachedFunction),
@@ -114,6 +139,7 @@ pub struct CacheData {
 
 /// Cached representation of a model field
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedField {
     pub name: String,
     pub field_type: String,
@@ -129,8 +155,49 @@ pub struct CachedField {
     pub translate: bool,
 }
 
+impl CachedField {
+    fn from_field_info(f: &crate::core::model::FieldInfo) -> Self {
+        Self {
+            name: f.name.to_string(),
+            field_type: f.field_type.clone(),
+            string: f.string.clone(),
+            required: f.required,
+            readonly: f.readonly,
+            compute: f.compute.clone(),
+            inverse: f.inverse.clone(),
+            related: f.related.clone(),
+            default: f.default.clone(),
+            store: f.store,
+            help: f.help.clone(),
+            translate: f.translate,
+        }
+    }
+
+    /// Inverse of [`Self::from_field_info`]. `symbol` is left unset (a blank `Weak`) since the
+    /// field's `VariableSymbol` doesn't exist yet at this point in the restore - callers wire it
+    /// up afterwards once the class's own symbols have been rebuilt.
+    fn to_field_info(&self) -> crate::core::model::FieldInfo {
+        crate::core::model::FieldInfo {
+            name: crate::oyarn!("{}", self.name),
+            field_type: self.field_type.clone(),
+            string: self.string.clone(),
+            required: self.required,
+            readonly: self.readonly,
+            compute: self.compute.clone(),
+            inverse: self.inverse.clone(),
+            related: self.related.clone(),
+            default: self.default.clone(),
+            store: self.store,
+            help: self.help.clone(),
+            translate: self.translate,
+            symbol: std::rc::Weak::new(),
+        }
+    }
+}
+
 /// Cached representation of an Odoo model
 #[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedModel {
     pub name: String,
     pub description: String,
@@ -149,7 +216,8 @@ pub struct CachedModel {
 }
 
 /// Cached representation of an Odoo module
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[cfg_attr(feature = "rkyv-cache", derive(rkyv::Archive, rkyv::Serialize, rkyv::Deserialize))]
 pub struct CachedModule {
     pub name: String,
     pub path: String,
@@ -177,13 +245,98 @@ impl CacheData {
     }
 }
 
+/// Compression codec a cache file's frame header tags itself with. `Raw` writes plain bincode
+/// with no streaming encoder at all, kept around as codec id 0 so a reader can distinguish an
+/// explicitly-uncompressed frame from the even older, header-less files written before
+/// [`CacheConfig`] existed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheCodec {
+    Raw = 0,
+    Zstd = 1,
+    Gzip = 2,
+    Bzip2 = 3,
+}
+
+impl CacheCodec {
+    fn id(self) -> u8 {
+        self as u8
+    }
+
+    fn from_id(id: u8) -> Option<Self> {
+        match id {
+            0 => Some(CacheCodec::Raw),
+            1 => Some(CacheCodec::Zstd),
+            2 => Some(CacheCodec::Gzip),
+            3 => Some(CacheCodec::Bzip2),
+            _ => None,
+        }
+    }
+}
+
+/// Compression settings for [`CacheManager`]/[`ModuleCacheManager`]'s bincode-backed formats.
+/// Defaults to zstd level 3, since decode cost is negligible next to the disk/startup savings on
+/// big multi-module projects.
+#[derive(Debug, Clone, Copy)]
+pub struct CacheConfig {
+    pub codec: CacheCodec,
+    pub level: i32,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self { codec: CacheCodec::Zstd, level: 3 }
+    }
+}
+
+/// 4-byte magic at the start of every cache frame written under a [`CacheConfig`], so a reader
+/// can tell a new, codec-tagged file apart from an older, header-less raw-bincode one.
+const CACHE_FRAME_MAGIC: [u8; 4] = *b"OLSC";
+
+/// Writes the frame header (magic + `CACHE_VERSION` + 1-byte codec id) a compressed cache file
+/// starts with, then returns a boxed `Write` that encodes everything written to it afterwards
+/// with the requested codec.
+fn open_frame_writer<'a, W: std::io::Write + 'a>(mut writer: W, config: &CacheConfig) -> std::io::Result<Box<dyn std::io::Write + 'a>> {
+    writer.write_all(&CACHE_FRAME_MAGIC)?;
+    writer.write_all(&CACHE_VERSION.to_le_bytes())?;
+    writer.write_all(&[config.codec.id()])?;
+    Ok(match config.codec {
+        CacheCodec::Raw => Box::new(writer),
+        CacheCodec::Zstd => Box::new(zstd::stream::Encoder::new(writer, config.level)?.auto_finish()),
+        CacheCodec::Gzip => Box::new(flate2::write::GzEncoder::new(writer, flate2::Compression::new(config.level.max(0) as u32))),
+        CacheCodec::Bzip2 => Box::new(bzip2::write::BzEncoder::new(writer, bzip2::Compression::new(config.level.max(0) as u32))),
+    })
+}
+
+/// Reads a cache frame's header if `bytes` starts with [`CACHE_FRAME_MAGIC`], returning the
+/// matching decoder over the remaining bytes. Falls back to treating `bytes` as a header-less
+/// raw-bincode blob (the format every cache file had before codecs existed) when the magic isn't
+/// present, so old caches are still read rather than discarded.
+fn open_frame_reader(bytes: &[u8]) -> std::io::Result<Box<dyn std::io::Read + '_>> {
+    if bytes.len() >= 9 && bytes[0..4] == CACHE_FRAME_MAGIC {
+        let codec = CacheCodec::from_id(bytes[8]);
+        let payload = &bytes[9..];
+        return Ok(match codec {
+            Some(CacheCodec::Raw) | None => Box::new(payload),
+            Some(CacheCodec::Zstd) => Box::new(zstd::stream::Decoder::new(payload)?),
+            Some(CacheCodec::Gzip) => Box::new(flate2::read::GzDecoder::new(payload)),
+            Some(CacheCodec::Bzip2) => Box::new(bzip2::read::BzDecoder::new(payload)),
+        });
+    }
+    Ok(Box::new(bytes))
+}
+
 pub struct CacheManager {
     cache_dir: PathBuf,
     cache_path: PathBuf,
+    config: CacheConfig,
 }
 
 impl CacheManager {
     pub fn new() -> Option<Self> {
+        Self::new_with_config(CacheConfig::default())
+    }
+
+    pub fn new_with_config(config: CacheConfig) -> Option<Self> {
         let cache_dir = dirs::data_local_dir()?.join("odoo-ls");
         if !cache_dir.exists() {
             if let Err(e) = fs::create_dir_all(&cache_dir) {
@@ -192,7 +345,7 @@ impl CacheManager {
             }
         }
         let cache_path = cache_dir.join(CACHE_FILENAME);
-        Some(Self { cache_dir, cache_path })
+        Some(Self { cache_dir, cache_path, config })
     }
 
     pub fn load(&self, odoo_path: &str) -> Option<CacheData> {
@@ -201,15 +354,22 @@ impl CacheManager {
             return None;
         }
 
-        let file = match fs::File::open(&self.cache_path) {
-            Ok(f) => f,
+        let bytes = match fs::read(&self.cache_path) {
+            Ok(b) => b,
             Err(e) => {
                 warn!("Failed to open cache file: {}", e);
                 return None;
             }
         };
 
-        let reader = BufReader::new(file);
+        let reader = match open_frame_reader(&bytes) {
+            Ok(r) => r,
+            Err(e) => {
+                warn!("Failed to open cache decoder: {}", e);
+                return None;
+            }
+        };
+
         let cache: CacheData = match bincode::deserialize_from(reader) {
             Ok(c) => c,
             Err(e) => {
@@ -247,10 +407,21 @@ impl CacheManager {
         };
 
         let writer = BufWriter::new(file);
-        if let Err(e) = bincode::serialize_into(writer, cache) {
+        let mut encoder = match open_frame_writer(writer, &self.config) {
+            Ok(e) => e,
+            Err(e) => {
+                warn!("Failed to open cache encoder: {}", e);
+                return false;
+            }
+        };
+        if let Err(e) = bincode::serialize_into(&mut encoder, cache) {
             warn!("Failed to serialize cache: {}", e);
             return false;
         }
+        if let Err(e) = encoder.flush() {
+            warn!("Failed to finish compressing cache: {}", e);
+            return false;
+        }
 
         info!("Saved cache with {} file entries to {:?}", cache.files.len(), self.cache_path);
         true
@@ -267,13 +438,63 @@ impl CacheManager {
     }
 }
 
+/// One module's entry in the reverse-dependency manifest: the combined content hash it was last
+/// cached with, its transitive dependency list (`CachedModule::all_depends`), and the per-file
+/// hashes that hash was folded over - kept here so [`ModuleCacheManager::validate_all`] can
+/// recompute a module's *current* combined hash without loading its full symbol cache.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct ModuleManifestEntry {
+    combined_hash: u64,
+    all_depends: Vec<String>,
+    file_hashes: HashMap<String, u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct ModuleManifest {
+    modules: HashMap<String, ModuleManifestEntry>,
+}
+
+/// Folds a module's `file_hashes` (sorted by path, so the result doesn't depend on `HashMap`
+/// iteration order) and its `all_depends` list into one combined hash, used both when recording
+/// a module's manifest entry and when recomputing its current hash from disk.
+fn combined_module_hash(file_hashes: &HashMap<String, u64>, all_depends: &[String]) -> u64 {
+    let mut paths: Vec<&String> = file_hashes.keys().collect();
+    paths.sort();
+    let mut hasher = DefaultHasher::new();
+    for path in paths {
+        path.hash(&mut hasher);
+        file_hashes[path].hash(&mut hasher);
+    }
+    let mut depends = all_depends.to_vec();
+    depends.sort();
+    for dep in &depends {
+        dep.hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+/// On-disk wrapper around a [`CachedModule`] that carries the schema/server-version header
+/// `ModuleCacheManager` validates on load, so a cache built by an older odoo-ls (or an older
+/// `Cached*` schema) is discarded wholesale rather than partially restored.
+#[derive(Serialize, Deserialize, Debug)]
+struct ModuleCacheEnvelope {
+    format_version: u32,
+    server_version: String,
+    module: CachedModule,
+}
+
 #[derive(Debug)]
 pub struct ModuleCacheManager {
     cache_dir: PathBuf,
+    config: CacheConfig,
 }
 
 impl ModuleCacheManager {
     pub fn new() -> Option<Self> {
+        Self::new_with_config(CacheConfig::default())
+    }
+
+    pub fn new_with_config(config: CacheConfig) -> Option<Self> {
         let cache_dir = dirs::data_local_dir()?.join("odoo-ls").join("modules");
         if !cache_dir.exists() {
             if let Err(e) = fs::create_dir_all(&cache_dir) {
@@ -281,7 +502,7 @@ impl ModuleCacheManager {
                 return None;
             }
         }
-        Some(Self { cache_dir })
+        Some(Self { cache_dir, config })
     }
 
     pub fn get_module_cache_path(&self, module_name: &str, odoo_path: &str) -> PathBuf {
@@ -291,7 +512,25 @@ impl ModuleCacheManager {
 
     pub fn save_module(&self, module: &CachedModule, odoo_path: &str) -> bool {
         let cache_path = self.get_module_cache_path(&module.name, odoo_path);
-        let file = match fs::File::create(&cache_path) {
+
+        #[cfg(feature = "rkyv-cache")]
+        let saved = crate::core::rkyv_cache::save_module_rkyv(&cache_path, module);
+
+        #[cfg(not(feature = "rkyv-cache"))]
+        let saved = self.save_module_bincode(module, &cache_path);
+
+        if saved {
+            self.update_manifest_entry(module);
+        }
+        saved
+    }
+
+    /// The original eagerly-deserializing bincode envelope path, used by default (whenever the
+    /// `rkyv-cache` feature is off) so the mmap-backed format in
+    /// [`crate::core::rkyv_cache`] can be adopted gradually.
+    #[cfg(not(feature = "rkyv-cache"))]
+    fn save_module_bincode(&self, module: &CachedModule, cache_path: &Path) -> bool {
+        let file = match fs::File::create(cache_path) {
             Ok(f) => f,
             Err(e) => {
                 warn!("Failed to create module cache file {:?}: {}", cache_path, e);
@@ -299,43 +538,117 @@ impl ModuleCacheManager {
             }
         };
 
+        let envelope = ModuleCacheEnvelope {
+            format_version: MODULE_CACHE_FORMAT_VERSION,
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            module: module.clone(),
+        };
+
         let writer = BufWriter::new(file);
-        if let Err(e) = bincode::serialize_into(writer, module) {
+        let mut encoder = match open_frame_writer(writer, &self.config) {
+            Ok(e) => e,
+            Err(e) => {
+                warn!("Failed to open module cache encoder: {}", e);
+                return false;
+            }
+        };
+        if let Err(e) = bincode::serialize_into(&mut encoder, &envelope) {
             warn!("Failed to serialize module cache: {}", e);
             return false;
         }
+        if let Err(e) = encoder.flush() {
+            warn!("Failed to finish compressing module cache: {}", e);
+            return false;
+        }
 
         info!("Saved module cache for {} at {:?}", module.name, cache_path);
         true
     }
 
+    /// Loads and validates a module's cache, dropping any `CachedFile` whose on-disk source has
+    /// changed since it was cached (content hash mismatch) rather than restoring stale symbols.
+    ///
+    /// Under the `rkyv-cache` feature this is served from the mmap-backed archive (see
+    /// [`crate::core::rkyv_cache`]) and materialized into an owned [`CachedModule`] so the
+    /// revalidation/retain logic below, and every existing caller, stay unchanged; callers that
+    /// want the zero-copy view directly can use [`ModuleCacheManager::load_module_archived`]
+    /// instead.
     pub fn load_module(&self, module_name: &str, odoo_path: &str) -> Option<CachedModule> {
         let cache_path = self.get_module_cache_path(module_name, odoo_path);
 
-        if !cache_path.exists() {
-            return None;
-        }
+        #[cfg(feature = "rkyv-cache")]
+        let mut module = {
+            let handle = crate::core::rkyv_cache::load_module_rkyv(&cache_path)?;
+            let archived = handle.get();
+            rkyv::Deserialize::deserialize(archived, &mut rkyv::Infallible).ok()?
+        };
 
-        let file = match fs::File::open(&cache_path) {
-            Ok(f) => f,
-            Err(e) => {
-                warn!("Failed to open module cache file {:?}: {}", cache_path, e);
+        #[cfg(not(feature = "rkyv-cache"))]
+        let mut module = {
+            if !cache_path.exists() {
                 return None;
             }
-        };
 
-        let reader = BufReader::new(file);
-        let module: CachedModule = match bincode::deserialize_from(reader) {
-            Ok(m) => m,
-            Err(e) => {
-                warn!("Failed to deserialize module cache: {}", e);
+            let bytes = match fs::read(&cache_path) {
+                Ok(b) => b,
+                Err(e) => {
+                    warn!("Failed to open module cache file {:?}: {}", cache_path, e);
+                    return None;
+                }
+            };
+
+            let reader = match open_frame_reader(&bytes) {
+                Ok(r) => r,
+                Err(e) => {
+                    warn!("Failed to open module cache decoder: {}", e);
+                    return None;
+                }
+            };
+            let envelope: ModuleCacheEnvelope = match bincode::deserialize_from(reader) {
+                Ok(e) => e,
+                Err(e) => {
+                    warn!("Failed to deserialize module cache: {}", e);
+                    return None;
+                }
+            };
+
+            if envelope.format_version != MODULE_CACHE_FORMAT_VERSION {
+                info!("Module cache format mismatch for {} (got {}, expected {})", module_name, envelope.format_version, MODULE_CACHE_FORMAT_VERSION);
+                return None;
+            }
+
+            if envelope.server_version != env!("CARGO_PKG_VERSION") {
+                info!("Module cache server version mismatch for {} (got {}, expected {})", module_name, envelope.server_version, env!("CARGO_PKG_VERSION"));
                 return None;
             }
+
+            envelope.module
         };
 
+        let original_count = module.files.len();
+        module.files.retain(|cached_file| {
+            match hash_file_contents(&cached_file.path) {
+                Some(hash) => hash == cached_file.processed_text_hash,
+                None => false,
+            }
+        });
+        let dropped = original_count - module.files.len();
+        if dropped > 0 {
+            info!("Dropped {} stale file(s) from module cache for {} (source changed on disk)", dropped, module_name);
+        }
+
         Some(module)
     }
 
+    /// Returns the zero-copy archived view of a module's cache directly, without materializing
+    /// an owned [`CachedModule`] - only available under the `rkyv-cache` feature, since the
+    /// bincode envelope has no archived representation to borrow from.
+    #[cfg(feature = "rkyv-cache")]
+    pub fn load_module_archived(&self, module_name: &str, odoo_path: &str) -> Option<crate::core::rkyv_cache::ArchivedModuleHandle> {
+        let cache_path = self.get_module_cache_path(module_name, odoo_path);
+        crate::core::rkyv_cache::load_module_rkyv(&cache_path)
+    }
+
     pub fn invalidate_module(&self, module_name: &str, odoo_path: &str) {
         let cache_path = self.get_module_cache_path(module_name, odoo_path);
         if cache_path.exists() {
@@ -356,6 +669,136 @@ impl ModuleCacheManager {
             info!("Cleared all module caches");
         }
     }
+
+    fn manifest_path(&self) -> PathBuf {
+        self.cache_dir.join(MODULE_MANIFEST_FILENAME)
+    }
+
+    fn load_manifest(&self) -> ModuleManifest {
+        let path = self.manifest_path();
+        if !path.exists() {
+            return ModuleManifest::default();
+        }
+        let file = match fs::File::open(&path) {
+            Ok(f) => f,
+            Err(e) => {
+                warn!("Failed to open module manifest {:?}: {}", path, e);
+                return ModuleManifest::default();
+            }
+        };
+        match bincode::deserialize_from(BufReader::new(file)) {
+            Ok(manifest) => manifest,
+            Err(e) => {
+                warn!("Failed to deserialize module manifest: {}", e);
+                ModuleManifest::default()
+            }
+        }
+    }
+
+    fn save_manifest(&self, manifest: &ModuleManifest) -> bool {
+        let file = match fs::File::create(self.manifest_path()) {
+            Ok(f) => f,
+            Err(e) => {
+                warn!("Failed to create module manifest file: {}", e);
+                return false;
+            }
+        };
+        if let Err(e) = bincode::serialize_into(BufWriter::new(file), manifest) {
+            warn!("Failed to serialize module manifest: {}", e);
+            return false;
+        }
+        true
+    }
+
+    /// Records (or refreshes) `module`'s entry in the reverse-dependency manifest, combining its
+    /// `file_hashes` and `all_depends` into one hash. Called after every successful
+    /// [`ModuleCacheManager::save_module`] so the manifest never drifts from what's on disk.
+    pub fn update_manifest_entry(&self, module: &CachedModule) {
+        let mut manifest = self.load_manifest();
+        let combined_hash = combined_module_hash(&module.file_hashes, &module.all_depends);
+        manifest.modules.insert(module.name.clone(), ModuleManifestEntry {
+            combined_hash,
+            all_depends: module.all_depends.clone(),
+            file_hashes: module.file_hashes.clone(),
+        });
+        self.save_manifest(&manifest);
+    }
+
+    /// Invalidates `module_name`'s own cache, plus every module that (transitively, via
+    /// `all_depends`) depends on it - so a base module change doesn't leave downstream modules
+    /// serving stale inherited symbols. Returns the full set of module names that were removed.
+    pub fn invalidate_with_dependents(&self, module_name: &str, odoo_path: &str) -> Vec<String> {
+        let manifest = self.load_manifest();
+        let mut dirty: Vec<String> = vec![module_name.to_string()];
+        for (name, entry) in manifest.modules.iter() {
+            if name != module_name && entry.all_depends.iter().any(|d| d == module_name) {
+                dirty.push(name.clone());
+            }
+        }
+
+        for name in &dirty {
+            self.invalidate_module(name, odoo_path);
+        }
+
+        let mut manifest = manifest;
+        for name in &dirty {
+            manifest.modules.remove(name);
+        }
+        self.save_manifest(&manifest);
+
+        dirty
+    }
+
+    /// Recomputes every manifested module's combined hash from its current on-disk file contents,
+    /// marks any module whose hash changed as dirty, then marks every module that (transitively,
+    /// via `all_depends`) depends on a dirty module as dirty too - in one pass, since
+    /// `all_depends` is already the fully transitive dependency set. Deletes exactly those
+    /// modules' caches and returns their names, so the server knows what it must rebuild instead
+    /// of loading a cache it will immediately throw away.
+    pub fn validate_all(&self, odoo_path: &str) -> Vec<String> {
+        let manifest = self.load_manifest();
+
+        let mut dirty: std::collections::HashSet<String> = std::collections::HashSet::new();
+        for (name, entry) in manifest.modules.iter() {
+            let mut current_hashes = HashMap::new();
+            let mut any_missing = false;
+            for path in entry.file_hashes.keys() {
+                match hash_file_contents(path) {
+                    Some(hash) => { current_hashes.insert(path.clone(), hash); }
+                    None => { any_missing = true; break; }
+                }
+            }
+            if any_missing {
+                dirty.insert(name.clone());
+                continue;
+            }
+            let current_combined = combined_module_hash(&current_hashes, &entry.all_depends);
+            if current_combined != entry.combined_hash {
+                dirty.insert(name.clone());
+            }
+        }
+
+        for (name, entry) in manifest.modules.iter() {
+            if dirty.contains(name) {
+                continue;
+            }
+            if entry.all_depends.iter().any(|dep| dirty.contains(dep)) {
+                dirty.insert(name.clone());
+            }
+        }
+
+        let mut manifest = manifest;
+        for name in &dirty {
+            self.invalidate_module(name, odoo_path);
+            manifest.modules.remove(name);
+        }
+        if !dirty.is_empty() {
+            self.save_manifest(&manifest);
+            info!("validate_all: {} module(s) marked dirty and invalidated", dirty.len());
+        }
+
+        dirty.into_iter().collect()
+    }
 }
 
 pub fn get_file_metadata(path: &Path) -> Option<FileMetadata> {
@@ -378,6 +821,15 @@ pub fn is_file_unchanged(path: &str, cached: &FileMetadata) -> bool {
     }
 }
 
+/// Hashes a source file's current contents, used to revalidate a `CachedFile.processed_text_hash`
+/// against what's actually on disk before restoring its symbols from a module cache.
+pub(crate) fn hash_file_contents(path: &str) -> Option<u64> {
+    let content = fs::read_to_string(path).ok()?;
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    Some(hasher.finish())
+}
+
 impl CachedTextRange {
     pub fn from_text_range(range: ruff_text_size::TextRange) -> Self {
         Self {
@@ -406,16 +858,50 @@ impl CachedVariable {
     }
 }
 
+/// Best-effort dotted name for `sym` (e.g. `"Outer.Inner"`, `"int"`), built by walking `parent()`
+/// links up to the nearest file/package boundary (whose own name is not included) rather than
+/// resolving imports - good enough for
+/// the type hints stashed on a [`CachedArgument`], without needing a live `SessionInfo` to chase
+/// re-exports the way full reference resolution does.
+fn symbol_dotted_name(sym: &std::rc::Rc<std::cell::RefCell<crate::core::symbols::symbol::Symbol>>) -> String {
+    use crate::constants::SymType;
+
+    let mut parts = vec![sym.borrow().name().to_string()];
+    let mut current = sym.borrow().parent().and_then(|p| p.upgrade());
+    while let Some(parent) = current {
+        let typ = parent.borrow().typ();
+        if typ == SymType::FILE || matches!(typ, SymType::PACKAGE(_)) {
+            break;
+        }
+        parts.push(parent.borrow().name().to_string());
+        current = parent.borrow().parent().and_then(|p| p.upgrade());
+    }
+    parts.reverse();
+    parts.join(".")
+}
+
+/// Dotted name of the symbol `eval` already points to, if any - `None` for evaluations with no
+/// resolved symbol yet or none at all (e.g. most complex annotations/defaults, which need a
+/// session to evaluate and are simply left undescribed rather than guessed at).
+fn evaluation_dotted_name(eval: &crate::core::evaluation::Evaluation) -> Option<String> {
+    let sym = eval.get_symbol_weak()?.upgrade()?;
+    Some(symbol_dotted_name(&sym))
+}
+
 impl CachedFunction {
     pub fn from_function_symbol(func: &crate::core::symbols::function_symbol::FunctionSymbol) -> Self {
         let args: Vec<CachedArgument> = func.args.iter().map(|arg| {
             let name = arg.symbol.upgrade()
                 .map(|s| s.borrow().name().to_string())
                 .unwrap_or_default();
+            let annotation = arg.annotation.as_ref().and_then(evaluation_dotted_name);
+            let default_type = arg.default_value.as_ref().and_then(evaluation_dotted_name);
             CachedArgument {
                 name,
                 arg_type: format!("{:?}", arg.arg_type),
                 has_default: arg.default_value.is_some(),
+                annotation,
+                default_type,
             }
         }).collect();
 
@@ -447,7 +933,7 @@ impl CachedClass {
             description: m.description.clone(),
             inherit: m.inherit.iter().map(|s| s.to_string()).collect(),
             inherits: m.inherits.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect(),
-            fields: Vec::new(),
+            fields: m.fields.values().map(CachedField::from_field_info).collect(),
             is_abstract: m.is_abstract,
             transient: m.transient,
             table: m.table.clone(),
@@ -625,7 +1111,11 @@ pub fn restore_symbols_to_parent(
                 class_rc.borrow_mut().set_parent(Some(Rc::downgrade(&parent)));
 
                 restore_symbols_to_parent(&cc.symbols, class_rc.clone(), is_external);
-                
+
+                if let Some(cached_model) = &cc.model {
+                    restore_model_fields(cached_model, &class_rc);
+                }
+
                 add_symbol_to_parent(&parent, &class_rc, 0);
             }
         }
@@ -651,23 +1141,62 @@ fn restore_function_args(
         if let Some(param_sym) = content.symbols.first() {
             let param_sym_clone = param_sym.clone();
             let default_value = if cached_arg.has_default {
-                Some(Evaluation::new_none())
+                Some(match &cached_arg.default_type {
+                    Some(type_name) => Evaluation::new_type_hint(type_name),
+                    None => Evaluation::new_none(),
+                })
             } else {
                 None
             };
+            let annotation = cached_arg.annotation.as_ref().map(Evaluation::new_type_hint);
             let arg_type = cached_arg.to_argument_type();
-            
+
             drop(func);
             func_rc.borrow_mut().as_func_mut().args.push(Argument {
                 symbol: Rc::downgrade(&param_sym_clone),
                 default_value,
                 arg_type,
-                annotation: None,
+                annotation,
             });
         }
     }
 }
 
+/// Rebuilds `class._model.fields` from `cached_model.fields`, linking each [`FieldInfo`](crate::core::model::FieldInfo)
+/// back to the `VariableSymbol` the field is declared on (already restored onto `class_rc` by the
+/// caller's [`restore_symbols_to_parent`] pass) so completion and hover over `self.<field>` can
+/// read type/compute/related metadata straight off the model without re-evaluating the field
+/// assignment.
+fn restore_model_fields(
+    cached_model: &CachedModel,
+    class_rc: &std::rc::Rc<std::cell::RefCell<crate::core::symbols::symbol::Symbol>>,
+) {
+    use crate::core::symbols::symbol_mgr::SymbolMgr;
+    use crate::oyarn;
+    use std::rc::Rc;
+
+    let mut fields = std::collections::HashMap::new();
+    for cached_field in &cached_model.fields {
+        let mut field_info = cached_field.to_field_info();
+        let field_name = oyarn!("{}", cached_field.name);
+
+        let class = class_rc.borrow();
+        let class_sym = class.as_class_sym();
+        let content = class_sym.get_content_symbol(field_name.clone(), u32::MAX);
+        drop(class);
+
+        if let Some(field_sym) = content.symbols.first() {
+            field_info.symbol = Rc::downgrade(field_sym);
+        }
+
+        fields.insert(field_name, field_info);
+    }
+
+    if let Some(model_data) = class_rc.borrow_mut().as_class_sym_mut()._model.as_mut() {
+        model_data.fields = fields;
+    }
+}
+
 pub fn restore_file_from_cache(
     cached_file: &CachedFile,
     parent: std::rc::Rc<std::cell::RefCell<crate::core::symbols::symbol::Symbol>>,
@@ -699,26 +1228,57 @@ pub fn restore_file_from_cache(
 
 pub fn collect_files_recursively(
     module_symbols: &std::collections::HashMap<crate::constants::OYarn, std::rc::Rc<std::cell::RefCell<crate::core::symbols::symbol::Symbol>>>,
+) -> Vec<CachedFile> {
+    collect_files_recursively_with_ignore(module_symbols, &crate::core::ignore_config::IgnoreConfig::default())
+}
+
+/// Like [`collect_files_recursively`], but prunes both individual files and whole package
+/// subtrees that `ignore` rejects before a [`CachedFile`] is ever created - a subtree excluded at
+/// the `SymType::PACKAGE` level (e.g. a vendored addon under `node_modules/`) never even descends
+/// into its `module_symbols`, so the cost of walking it is avoided entirely rather than just
+/// discarding its files one by one.
+///
+/// This walk is, and stays, single-threaded. A work-stealing rewrite was attempted here, but
+/// every `Symbol` in the tree is `Rc<RefCell<_>>`, which is neither `Send` nor `Sync` - handing
+/// subtrees to worker threads doesn't compile, and faking batch-based concurrency without actual
+/// threads just relabels the same serial walk. Making this genuinely concurrent requires the
+/// symbol tree itself to move to an `Arc`/thread-safe interior-mutability representation first,
+/// which is a crate-wide migration, not something this cache-building entry point can take on by
+/// itself. Revisit parallelizing this once that migration happens; until then a correct serial
+/// walk is the honest deliverable.
+pub fn collect_files_recursively_with_ignore(
+    module_symbols: &std::collections::HashMap<crate::constants::OYarn, std::rc::Rc<std::cell::RefCell<crate::core::symbols::symbol::Symbol>>>,
+    ignore: &crate::core::ignore_config::IgnoreConfig,
 ) -> Vec<CachedFile> {
     use crate::core::symbols::symbol::Symbol;
     use crate::core::symbols::package_symbol::PackageSymbol;
     use crate::constants::SymType;
-    
+
     let mut cached_files = Vec::new();
-    
+
     for (_name, sym_rc) in module_symbols.iter() {
         let sym = sym_rc.borrow();
+        let path = sym.paths().first().cloned();
+        if let Some(path) = &path {
+            if let Some(glob) = ignore.matching_glob(path) {
+                info!("Skipping {} from the symbol cache: matches ignore glob {}", path, glob);
+                continue;
+            }
+        }
         match sym.typ() {
             SymType::FILE => {
+                if path.as_deref().is_some_and(|p| ignore.is_ignored(p)) {
+                    continue;
+                }
                 cached_files.push(CachedFile::from_file_symbol(sym.as_file()));
             }
             SymType::PACKAGE(_) => {
                 match &*sym {
                     Symbol::Package(PackageSymbol::PythonPackage(p)) => {
-                        cached_files.extend(collect_files_recursively(&p.module_symbols));
+                        cached_files.extend(collect_files_recursively_with_ignore(&p.module_symbols, ignore));
                     }
                     Symbol::Package(PackageSymbol::Module(m)) => {
-                        cached_files.extend(collect_files_recursively(&m.module_symbols));
+                        cached_files.extend(collect_files_recursively_with_ignore(&m.module_symbols, ignore));
                     }
                     _ => {}
                 }
@@ -726,6 +1286,7 @@ pub fn collect_files_recursively(
             _ => {}
         }
     }
-    
+
     cached_files
 }
+
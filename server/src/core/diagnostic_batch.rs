@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use crate::threads::SessionInfo;
+
+/// Custom notification sent once `process_rebuilds` reaches a quiescent state, so a client (or
+/// the test setup module) can wait for a specific `batch_id` instead of assuming a fixed number
+/// of `PublishDiagnostics` messages will arrive.
+pub const DIAGNOSTIC_BATCH_METHOD: &str = "$Odoo/diagnosticBatch";
+
+#[derive(Serialize, Debug, Clone)]
+pub struct DiagnosticBatchParams {
+    pub batch_id: u64,
+    pub uris: Vec<String>,
+}
+
+/// Hands out monotonically increasing batch ids and emits the notification once a rebuild
+/// settles. One instance lives on `SyncOdoo`, alongside the other build-state counters.
+#[derive(Debug, Default)]
+pub struct DiagnosticBatchTracker {
+    next_batch_id: u64,
+}
+
+impl DiagnosticBatchTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Publishes a batch notification for every uri whose diagnostics were (re)published since
+    /// the last quiescent point, and returns the id that was assigned to it.
+    pub fn publish_batch(&mut self, session: &SessionInfo, uris: Vec<String>) -> u64 {
+        let batch_id = self.next_batch_id;
+        self.next_batch_id += 1;
+        session.send_notification(DIAGNOSTIC_BATCH_METHOD, DiagnosticBatchParams { batch_id, uris });
+        batch_id
+    }
+}
+
+/// Debounces `handle_did_change` so a file being typed into doesn't get a full diagnostic pass
+/// re-run on every keystroke: each edit schedules (or re-schedules) a run `debounce` in the
+/// future, and a burst of edits to the same file coalesces into the single run scheduled by the
+/// last one. Each scheduled run is stamped with a "changes token" - if the file advances again
+/// before that run starts, [`Self::is_current`] lets the stale run recognize it's been superseded
+/// and drop its results instead of publishing them over a newer edit's.
+#[derive(Debug)]
+pub struct DiagnosticDebouncer {
+    debounce: Duration,
+    /// Per-path: the token of the most recent edit, and when its debounce window expires.
+    pending: HashMap<String, (u64, Instant)>,
+    /// Per-path: the token of the most recent edit, kept after [`Self::take_ready`] so a run
+    /// already in flight can still be checked with [`Self::is_current`].
+    latest: HashMap<String, u64>,
+    next_token: u64,
+}
+
+impl DiagnosticDebouncer {
+    pub fn new(debounce: Duration) -> Self {
+        Self { debounce, pending: HashMap::new(), latest: HashMap::new(), next_token: 0 }
+    }
+
+    /// Call on every `handle_did_change` for `path`. Returns the token this edit was assigned;
+    /// a run that was already pending for `path` is superseded (its token is now stale) and its
+    /// debounce window is reset to start counting down again from now.
+    pub fn schedule(&mut self, path: &str) -> u64 {
+        let token = self.next_token;
+        self.next_token += 1;
+        self.pending.insert(path.to_string(), (token, Instant::now() + self.debounce));
+        self.latest.insert(path.to_string(), token);
+        token
+    }
+
+    /// Whether `token` (the one returned by the [`Self::schedule`] call that kicked off this run)
+    /// is still the latest one recorded for `path` - `false` means a later edit has since
+    /// rescheduled the same file, so this run's diagnostics are stale and should be discarded
+    /// rather than published.
+    pub fn is_current(&self, path: &str, token: u64) -> bool {
+        self.latest.get(path).is_some_and(|current| *current == token)
+    }
+
+    /// Every `(path, token)` whose debounce window has elapsed and hasn't been run yet, removed
+    /// from the pending set - the driver loop calls this on each tick to find what's ready.
+    pub fn take_ready(&mut self) -> Vec<(String, u64)> {
+        let now = Instant::now();
+        let ready: Vec<String> = self.pending.iter()
+            .filter(|(_, (_, due))| *due <= now)
+            .map(|(path, _)| path.clone())
+            .collect();
+        ready.into_iter().filter_map(|path| {
+            self.pending.remove(&path).map(|(token, _)| (path, token))
+        }).collect()
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::{HashMap, HashSet};
+
+use lsp_types::Diagnostic;
+
+/// Identifies which analysis pass produced a diagnostic, so diagnostics from unrelated passes
+/// (Python `OLS020xx` codes, XML `OLS050xx` codes, manifest checks, import resolution) can be
+/// merged together for a file instead of one pass's `PublishDiagnostics` clobbering another's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticSource {
+    PythonAnalysis,
+    Xml,
+    Manifest,
+    Import,
+}
+
+/// Per-file, per-source diagnostics, merged on publish and guarded against stale writes: a
+/// `replace` for a document version older than the one already recorded for that path is
+/// dropped, so a slow Python-analysis pass can't overwrite XML/manifest findings computed
+/// against a newer edit of the same file.
+#[derive(Debug, Default)]
+pub struct DiagnosticCollection {
+    versions: HashMap<String, Option<i32>>,
+    entries: HashMap<(String, DiagnosticSource), Vec<Diagnostic>>,
+    /// Paths whose merged diagnostics changed since the last [`Self::take_dirty`] drain - so the
+    /// publish loop doesn't have to re-merge and re-send every known path on every tick, just the
+    /// ones a source actually touched.
+    dirty: HashSet<String>,
+}
+
+impl DiagnosticCollection {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records (or replaces) the diagnostics a given source found for `path`, computed against
+    /// `version` of the file. If a newer version has already been recorded for `path`, the
+    /// write is dropped and `false` is returned: the caller computed against a superseded
+    /// snapshot and should not publish.
+    pub fn replace(&mut self, path: &str, source: DiagnosticSource, version: Option<i32>, diagnostics: Vec<Diagnostic>) -> bool {
+        if let Some(current) = self.versions.get(path).copied().flatten() {
+            if let Some(version) = version {
+                if version < current {
+                    return false;
+                }
+            }
+        }
+        self.versions.insert(path.to_string(), version);
+        self.entries.insert((path.to_string(), source), diagnostics);
+        self.dirty.insert(path.to_string());
+        true
+    }
+
+    pub fn clear_source(&mut self, path: &str, source: DiagnosticSource) {
+        self.entries.remove(&(path.to_string(), source));
+        self.dirty.insert(path.to_string());
+    }
+
+    pub fn clear_path(&mut self, path: &str) {
+        self.versions.remove(path);
+        self.entries.retain(|(entry_path, _), _| entry_path != path);
+        self.dirty.insert(path.to_string());
+    }
+
+    /// Drains and returns every path marked dirty since the last call, so a publish loop can ask
+    /// "what actually changed" instead of re-merging and re-publishing every known file on every
+    /// pass.
+    pub fn take_dirty(&mut self) -> Vec<String> {
+        self.dirty.drain().collect()
+    }
+
+    /// The raw, unmerged diagnostics one specific source recorded for `path`, for test assertions
+    /// that care about what a single analysis pass produced rather than the merged publish set.
+    pub fn diagnostics_for_source(&self, path: &str, source: DiagnosticSource) -> Option<&[Diagnostic]> {
+        self.entries.get(&(path.to_string(), source)).map(Vec::as_slice)
+    }
+
+    /// Merges every source's diagnostics for `path` into a single vector, in a stable
+    /// `PythonAnalysis -> Xml -> Manifest -> Import` order so results are deterministic across
+    /// publishes regardless of which analysis pass finished last.
+    pub fn merged(&self, path: &str) -> Vec<Diagnostic> {
+        const ORDER: [DiagnosticSource; 4] = [
+            DiagnosticSource::PythonAnalysis,
+            DiagnosticSource::Xml,
+            DiagnosticSource::Manifest,
+            DiagnosticSource::Import,
+        ];
+        let mut merged = Vec::new();
+        for source in ORDER {
+            if let Some(diagnostics) = self.entries.get(&(path.to_string(), source)) {
+                merged.extend(diagnostics.iter().cloned());
+            }
+        }
+        merged
+    }
+
+    pub fn version_for(&self, path: &str) -> Option<i32> {
+        self.versions.get(path).copied().flatten()
+    }
+}
@@ -0,0 +1,235 @@
+//! Central registry of every `OLS*` diagnostic code this server can emit, modeled on rustc's
+//! error-code registry: a code can't be turned into a [`Diagnostic`] without going through
+//! [`create_diagnostic`], and `create_diagnostic` can't build one for a [`DiagnosticCode`] that
+//! isn't registered in [`explanation`] - so the registry is always in sync with what the server
+//! actually produces, and `--explain OLS01000`-style tooling has one place to read from.
+
+use lsp_types::{CodeDescription, Diagnostic, DiagnosticSeverity, NumberOrString, Uri};
+use serde::{Deserialize, Serialize};
+
+use crate::threads::SessionInfo;
+
+/// Custom request a client sends to resolve a `codeDescription`/"learn more" click (or a `--explain
+/// OLS01000` CLI invocation) into the code's full write-up, mirroring
+/// [`crate::core::diagnostic_batch::DIAGNOSTIC_BATCH_METHOD`]'s convention of a plain `$Odoo/...`
+/// method name alongside its params/result types.
+pub const EXPLAIN_DIAGNOSTIC_METHOD: &str = "$Odoo/explainDiagnostic";
+
+#[derive(Deserialize, Debug, Clone)]
+pub struct ExplainDiagnosticParams {
+    pub code: String,
+}
+
+#[derive(Serialize, Debug, Clone)]
+pub struct ExplainDiagnosticResult {
+    pub code: String,
+    pub title: String,
+    pub description: String,
+    pub example_and_fix: String,
+}
+
+/// Handles an [`EXPLAIN_DIAGNOSTIC_METHOD`] request: looks `params.code` up against every
+/// registered [`DiagnosticCode`], returning `None` for a code this server never emits (including
+/// a typo'd one) rather than a hand-written "not found" explanation.
+pub fn handle_explain_request(params: &ExplainDiagnosticParams) -> Option<ExplainDiagnosticResult> {
+    let code = ALL_DIAGNOSTIC_CODES.iter().copied().find(|c| c.as_str() == params.code)?;
+    let info = explanation(code);
+    Some(ExplainDiagnosticResult {
+        code: info.code.as_str().to_string(),
+        title: info.title.to_string(),
+        description: info.description.to_string(),
+        example_and_fix: info.example_and_fix.to_string(),
+    })
+}
+
+/// Every registered code, for [`handle_explain_request`] and anything else (a future `--list-codes`
+/// CLI flag, tests) that needs to enumerate the registry rather than look up one code at a time.
+pub const ALL_DIAGNOSTIC_CODES: &[DiagnosticCode] = &[
+    DiagnosticCode::OLS01000,
+    DiagnosticCode::OLS01007,
+    DiagnosticCode::OLS02001,
+    DiagnosticCode::OLS05001,
+    DiagnosticCode::OLS05002,
+    DiagnosticCode::OLS06001,
+];
+
+/// Every diagnostic code this server is able to emit. Adding a variant here without adding a
+/// matching arm to [`explanation`] is a compile error (the match in `explanation` is exhaustive),
+/// which is what keeps the registry from drifting out of sync with reality.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiagnosticCode {
+    OLS01000,
+    OLS01007,
+    OLS02001,
+    OLS05001,
+    OLS05002,
+    OLS06001,
+}
+
+impl DiagnosticCode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::OLS01000 => "OLS01000",
+            Self::OLS01007 => "OLS01007",
+            Self::OLS02001 => "OLS02001",
+            Self::OLS05001 => "OLS05001",
+            Self::OLS05002 => "OLS05002",
+            Self::OLS06001 => "OLS06001",
+        }
+    }
+}
+
+/// A code's full, structured write-up: what rustc calls its "long" explanation. `message_template`
+/// is the short, one-line diagnostic text, with `{}` placeholders filled positionally from the
+/// `args` passed to [`create_diagnostic`].
+pub struct DiagnosticExplanation {
+    pub code: DiagnosticCode,
+    pub title: &'static str,
+    pub message_template: &'static str,
+    pub default_severity: DiagnosticSeverity,
+    pub description: &'static str,
+    pub example_and_fix: &'static str,
+}
+
+/// The single source of truth this module is built around: every [`DiagnosticCode`] variant maps
+/// to exactly one explanation here. Exhaustive on `code` so a new variant can't compile without
+/// also being documented.
+pub fn explanation(code: DiagnosticCode) -> DiagnosticExplanation {
+    match code {
+        DiagnosticCode::OLS01000 => DiagnosticExplanation {
+            code,
+            title: "Python syntax error",
+            message_template: "Syntax error",
+            default_severity: DiagnosticSeverity::ERROR,
+            description: "The file could not be parsed as valid Python. The server falls back to \
+                           whatever was last successfully parsed for completion/hover, but \
+                           diagnostics, symbol indexing and navigation for this file are stale \
+                           until the error is fixed.",
+            example_and_fix: "```python\ndef foo(:\n    pass\n```\nFix the syntax error reported \
+                               in the diagnostic's message (here, a missing parameter name before \
+                               the closing `)`).",
+        },
+        DiagnosticCode::OLS01007 => DiagnosticExplanation {
+            code,
+            title: "Missing `@classmethod` decorator",
+            message_template: "`{}` is called as a classmethod but isn't decorated with `@classmethod`",
+            default_severity: DiagnosticSeverity::WARNING,
+            description: "Odoo calls a handful of methods (`_register_hook`, `_setup_complete`, ...) \
+                           as classmethods even on classes that don't explicitly decorate them that \
+                           way. Relying on that implicit behavior works at runtime but hides the \
+                           method's actual signature from readers and from this server's own \
+                           resolution of `cls`/`self`.",
+            example_and_fix: "```python\nclass Foo(models.Model):\n    def _register_hook(cls):\n        ...\n```\n\
+                               Add `@classmethod` above the method so the first argument's type is \
+                               unambiguous.",
+        },
+        DiagnosticCode::OLS02001 => DiagnosticExplanation {
+            code,
+            title: "Compute/related/inverse/search method not found",
+            message_template: "`{}` has no method `{}`",
+            default_severity: DiagnosticSeverity::ERROR,
+            description: "A field's `compute=`/`related=`/`inverse=`/`search=` argument names a \
+                           method that isn't declared anywhere on the model (including its parents), \
+                           so Odoo will raise at registry build time rather than when the field is \
+                           first read.",
+            example_and_fix: "```python\nname = fields.Char(compute=\"_comp\")\n```\nDeclare the \
+                               missing method on the model, e.g. `def _comp(self): ...`.",
+        },
+        DiagnosticCode::OLS05001 => DiagnosticExplanation {
+            code,
+            title: "Malformed XML data file",
+            message_template: "Malformed XML: {}",
+            default_severity: DiagnosticSeverity::ERROR,
+            description: "The file could not be parsed as well-formed XML, so none of its \
+                           `<record>`/`<field>`/`<template>`/`<menuitem>` elements were indexed.",
+            example_and_fix: "```xml\n<record id=\"my_record\" model=\"res.partner\">\n    <field \
+                               name=\"name\">Acme</field>\n<!-- missing </record> -->\n```\nClose \
+                               every opened tag.",
+        },
+        DiagnosticCode::OLS05002 => DiagnosticExplanation {
+            code,
+            title: "Duplicate external id",
+            message_template: "Duplicate id `{}`",
+            default_severity: DiagnosticSeverity::ERROR,
+            description: "A `<record>`/`<menuitem>` id must be unique within the module that \
+                           declares it; Odoo uses it as the primary key for the external-id \
+                           registry (`ir.model.data`), and a second declaration silently shadows \
+                           the first one at load time.",
+            example_and_fix: "```xml\n<record id=\"my_record\" model=\"res.partner\">...</record>\n\
+                               <record id=\"my_record\" model=\"res.users\">...</record>\n```\n\
+                               Rename one of the two ids.",
+        },
+        DiagnosticCode::OLS06001 => DiagnosticExplanation {
+            code,
+            title: "CSV column count mismatch",
+            message_template: "Row has {} columns, expected {} (from the header)",
+            default_severity: DiagnosticSeverity::WARNING,
+            description: "Every data row in a CSV data file (e.g. `ir.model.access.csv`) must have \
+                           the same number of columns as the header row.",
+            example_and_fix: "```csv\nid,name,model_id:id\naccess_foo,Foo,model_foo\nbad_row,Bar\n```\n\
+                               Add the missing `model_id:id` value to the `bad_row` row.",
+        },
+    }
+}
+
+/// Replaces each `{}` in `template` with the corresponding entry of `args`, in order. Falls back
+/// to leaving a trailing `{}` untouched if there are more placeholders than arguments, rather than
+/// panicking - a malformed template should never take down diagnostic reporting.
+fn render_message(template: &str, args: &[&str]) -> String {
+    let mut result = String::with_capacity(template.len());
+    let mut args = args.iter();
+    let mut rest = template;
+    while let Some(pos) = rest.find("{}") {
+        result.push_str(&rest[..pos]);
+        match args.next() {
+            Some(arg) => result.push_str(arg),
+            None => result.push_str("{}"),
+        }
+        rest = &rest[pos + 2..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Builds the base [`Diagnostic`] for `code` - message rendered from its registered template and
+/// `args`, code/source/severity/codeDescription filled in - for the caller to layer a `range`
+/// (and anything else) on top of via `Diagnostic { range, ..diagnostic_base }`.
+///
+/// Returns `None` when the user's [`crate::core::file_mgr::DiagnosticsConfig`] has this code's
+/// severity overridden to `Off`, so a suppressed code never even gets far enough to be built,
+/// let alone published.
+pub fn create_diagnostic(session: &SessionInfo, code: DiagnosticCode, args: &[&str]) -> Option<Diagnostic> {
+    let info = explanation(code);
+    let severity_override = session.sync_odoo.config.diagnostics_config.overrides.get(info.code.as_str()).copied();
+    let severity = match severity_override {
+        Some(severity_override) => severity_override.to_lsp_severity()?,
+        None => info.default_severity,
+    };
+
+    Some(Diagnostic {
+        range: Default::default(),
+        severity: Some(severity),
+        code: Some(NumberOrString::String(info.code.as_str().to_string())),
+        code_description: Some(CodeDescription { href: explain_uri(info.code) }),
+        source: Some("odoo-ls".to_string()),
+        message: render_message(info.message_template, args),
+        ..Default::default()
+    })
+}
+
+/// Virtual, non-web URI resolved by the [`EXPLAIN_DIAGNOSTIC_METHOD`] server request rather than a
+/// page the client navigates to directly - clients that support `codeDescription` are expected to
+/// turn a click on it into that request instead of opening it as a browser link.
+fn explain_uri(code: DiagnosticCode) -> Uri {
+    format!("odools-explain:{}", code.as_str()).parse().expect("static explain URI is always valid")
+}
+
+/// Which diagnostic severities a [`crate::core::config::DiagnosticFilter`] can filter on - kept
+/// separate from [`lsp_types::DiagnosticSeverity`] so the config layer doesn't depend on `lsp_types`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSetting {
+    Error,
+    Warning,
+    Info,
+    Hint,
+}
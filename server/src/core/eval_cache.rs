@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::core::evaluation::Evaluation;
+use crate::utils::PathSanitizer;
+
+/// Magic number written at the start of the on-disk evaluation cache, so a truncated or foreign
+/// file is reported as a miss instead of producing a confusing bincode error. Mirrors
+/// `persist.rs`'s `CACHE_MAGIC`.
+const EVAL_CACHE_MAGIC: u32 = 0x4F4C_5345; // "OLSE"
+/// Bumped whenever the binary layout of [`Evaluation`] (or anything it contains) changes in a way
+/// that isn't backward-compatible, so a cache written by an older/newer server is discarded
+/// wholesale rather than mis-parsed.
+const EVAL_CACHE_FORMAT_VERSION: u32 = 1;
+const EVAL_CACHE_FILENAME: &str = "odoo_ls_eval_cache.bin";
+
+/// One file's worth of cached AST evaluations, mirroring `Symbol::File`'s in-memory
+/// `ast_eval_cache` (keyed by the byte offset `Evaluation::eval_from_ast` was called at), tagged
+/// with a blake3 hash of the source it was computed from so a file that changed since is never
+/// trusted blindly.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CachedEvalFile {
+    pub path: String,
+    pub content_hash: [u8; 32],
+    pub evaluations: HashMap<u32, Evaluation>,
+}
+
+/// Errors reading or writing the evaluation cache file. Every variant is handled the same way by
+/// callers: log it and fall back to re-inferring from source, rather than treating it as fatal.
+#[derive(Debug)]
+pub enum EvalCacheError {
+    Io(io::Error),
+    /// The file doesn't start with our magic number, or ended before a required field could be read.
+    Corrupt(String),
+    /// The cache was written by a different format version and cannot be trusted.
+    VersionMismatch { found: u32, expected: u32 },
+}
+
+impl std::fmt::Display for EvalCacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalCacheError::Io(e) => write!(f, "eval cache I/O error: {}", e),
+            EvalCacheError::Corrupt(msg) => write!(f, "corrupt eval cache file: {}", msg),
+            EvalCacheError::VersionMismatch { found, expected } => write!(f, "eval cache format version mismatch (found {}, expected {})", found, expected),
+        }
+    }
+}
+
+impl std::error::Error for EvalCacheError {}
+
+impl From<io::Error> for EvalCacheError {
+    fn from(e: io::Error) -> Self {
+        EvalCacheError::Io(e)
+    }
+}
+
+fn write_header<W: Write>(writer: &mut W) -> io::Result<()> {
+    writer.write_all(&EVAL_CACHE_MAGIC.to_le_bytes())?;
+    writer.write_all(&EVAL_CACHE_FORMAT_VERSION.to_le_bytes())?;
+    let server_version = env!("CARGO_PKG_VERSION").as_bytes();
+    writer.write_all(&(server_version.len() as u32).to_le_bytes())?;
+    writer.write_all(server_version)?;
+    Ok(())
+}
+
+fn read_header<R: Read>(reader: &mut R) -> Result<String, EvalCacheError> {
+    let mut magic_buf = [0u8; 4];
+    reader.read_exact(&mut magic_buf)?;
+    if u32::from_le_bytes(magic_buf) != EVAL_CACHE_MAGIC {
+        return Err(EvalCacheError::Corrupt("bad magic number".to_string()));
+    }
+    let mut version_buf = [0u8; 4];
+    reader.read_exact(&mut version_buf)?;
+    let found_version = u32::from_le_bytes(version_buf);
+    if found_version != EVAL_CACHE_FORMAT_VERSION {
+        return Err(EvalCacheError::VersionMismatch { found: found_version, expected: EVAL_CACHE_FORMAT_VERSION });
+    }
+    let mut len_buf = [0u8; 4];
+    reader.read_exact(&mut len_buf)?;
+    let mut version_bytes = vec![0u8; u32::from_le_bytes(len_buf) as usize];
+    reader.read_exact(&mut version_bytes)?;
+    String::from_utf8(version_bytes).map_err(|e| EvalCacheError::Corrupt(e.to_string()))
+}
+
+/// Persistent on-disk mirror of every `Symbol::File`'s in-memory `ast_eval_cache`, reloaded on
+/// startup so a fresh server doesn't pay to re-infer every file in the workspace from scratch.
+/// One cache file per workspace (keyed by its own path), written with a small fixed header
+/// (format version + server version) followed by the per-file records - the same dirstate-v2
+/// shape Mercurial uses for its own on-disk index.
+#[derive(Debug)]
+pub struct EvalCacheManager {
+    cache_path: PathBuf,
+}
+
+impl EvalCacheManager {
+    pub fn new(odoo_path: &str) -> Option<Self> {
+        let cache_dir = dirs::data_local_dir()?.join("odoo-ls").join("eval");
+        if !cache_dir.exists() {
+            if let Err(e) = fs::create_dir_all(&cache_dir) {
+                warn!("Failed to create evaluation cache directory: {}", e);
+                return None;
+            }
+        }
+        let hash = format!("{:x}", md5::compute(odoo_path.as_bytes()));
+        Some(Self { cache_path: cache_dir.join(format!("{}_{}", hash, EVAL_CACHE_FILENAME)) })
+    }
+
+    /// Serializes every entry in `files` to disk, overwriting whatever was cached before.
+    pub fn save(&self, files: &[CachedEvalFile]) -> Result<(), EvalCacheError> {
+        let file = File::create(&self.cache_path)?;
+        let mut writer = BufWriter::new(file);
+        write_header(&mut writer)?;
+        bincode::serialize_into(&mut writer, &files.len()).map_err(|e| EvalCacheError::Corrupt(e.to_string()))?;
+        for entry in files {
+            bincode::serialize_into(&mut writer, entry).map_err(|e| EvalCacheError::Corrupt(e.to_string()))?;
+        }
+        writer.flush()?;
+        info!("Saved {} file(s) to the evaluation cache", files.len());
+        Ok(())
+    }
+
+    /// Loads every record from disk, keeping only the ones whose stored `content_hash` still
+    /// matches `current_hashes` (sanitized path -> blake3 hash of the file's current source).
+    /// A version mismatch, a missing file, or a stale record is treated as a miss and dropped
+    /// rather than surfaced as an error - the caller just re-infers. A corrupt record is logged
+    /// and ends the load there: it and every record after it are dropped.
+    pub fn load(&self, current_hashes: &HashMap<String, [u8; 32]>) -> Vec<CachedEvalFile> {
+        let file = match File::open(&self.cache_path) {
+            Ok(f) => f,
+            Err(_) => return Vec::new(),
+        };
+        let mut reader = BufReader::new(file);
+        match read_header(&mut reader) {
+            Ok(_) => {}
+            Err(e) => {
+                info!("Discarding evaluation cache: {}", e);
+                return Vec::new();
+            }
+        }
+
+        let count: usize = match bincode::deserialize_from(&mut reader) {
+            Ok(c) => c,
+            Err(e) => {
+                warn!("Failed to read evaluation cache entry count: {}", e);
+                return Vec::new();
+            }
+        };
+
+        let mut kept = Vec::with_capacity(count);
+        for _ in 0..count {
+            let entry: CachedEvalFile = match bincode::deserialize_from(&mut reader) {
+                Ok(e) => e,
+                Err(e) => {
+                    warn!("Failed to deserialize an evaluation cache entry, stopping early: {}", e);
+                    break;
+                }
+            };
+            if current_hashes.get(&entry.path) == Some(&entry.content_hash) {
+                kept.push(entry);
+            }
+        }
+        info!("Reloaded {} file(s) from the evaluation cache ({} discarded as stale)", kept.len(), count - kept.len());
+        kept
+    }
+}
+
+/// Hashes `source` with blake3, the same way [`CachedEvalFile::content_hash`] is computed, so
+/// callers can compare a file's current content against what a cache entry was built from.
+pub fn hash_source(source: &str) -> [u8; 32] {
+    *blake3::hash(source.as_bytes()).as_bytes()
+}
+
+/// Sanitizes `path` the same way every other on-disk cache key in this crate does, so an entry
+/// written on one OS still matches when reloaded on another.
+pub fn cache_key_for_path(path: &str) -> String {
+    PathBuf::from(path).sanitize()
+}
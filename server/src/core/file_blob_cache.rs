@@ -0,0 +1,191 @@
+//! Persistent on-disk store for the flat `CachedFile` list `collect_files_recursively` produces,
+//! so a cold start doesn't have to re-parse the whole workspace. The blob is a header table
+//! (`path -> offset/length/mtime/hash`) followed by each file's own bincode-serialized record,
+//! `mmap`'d back on the next launch so entries that still match their source file are read
+//! straight out of the mapping instead of going through a `BufReader`.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use memmap2::Mmap;
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+use crate::core::cache::{hash_file_contents, CachedFile};
+
+/// Magic number at the start of a file blob cache, distinct from every other on-disk cache format
+/// in this crate so the two are never confused with each other.
+const FILE_BLOB_MAGIC: u32 = 0x4F4C_5346; // "OLSF"
+/// Bumped whenever the header/record layout below changes in a way older blobs can't be read
+/// back from.
+const FILE_BLOB_VERSION: u32 = 1;
+/// magic (4) + version (4) + header length (8)
+const HEADER_PREFIX_LEN: usize = 4 + 4 + 8;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+struct FileBlobEntry {
+    path: String,
+    offset: u64,
+    length: u64,
+    mtime: u64,
+    hash: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+struct FileBlobHeader {
+    entries: Vec<FileBlobEntry>,
+}
+
+fn file_mtime_secs(path: &str) -> Option<u64> {
+    let meta = fs::metadata(path).ok()?;
+    let modified = meta.modified().ok()?;
+    modified.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// Serializes `files` into one blob at `path`: a bincode-encoded header table of
+/// `path -> (offset, length, mtime, hash)` records, followed by each file's own
+/// bincode-serialized [`CachedFile`] back to back at those offsets. A file that fails to
+/// serialize is skipped (and logged) rather than aborting the whole blob.
+pub fn save_file_blob(path: &Path, files: &[CachedFile]) -> bool {
+    let mut body = Vec::new();
+    let mut entries = Vec::with_capacity(files.len());
+
+    for file in files {
+        let encoded = match bincode::serialize(file) {
+            Ok(b) => b,
+            Err(e) => {
+                warn!("Failed to serialize cached file {} for blob store: {}", file.path, e);
+                continue;
+            }
+        };
+        let offset = body.len() as u64;
+        let mtime = file_mtime_secs(&file.path).unwrap_or(0);
+        let hash = hash_file_contents(&file.path).unwrap_or(0);
+        entries.push(FileBlobEntry {
+            path: file.path.clone(),
+            offset,
+            length: encoded.len() as u64,
+            mtime,
+            hash,
+        });
+        body.extend_from_slice(&encoded);
+    }
+
+    let header_bytes = match bincode::serialize(&FileBlobHeader { entries }) {
+        Ok(b) => b,
+        Err(e) => {
+            warn!("Failed to serialize file blob cache header: {}", e);
+            return false;
+        }
+    };
+
+    let mut out = match File::create(path) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!("Failed to create file blob cache {:?}: {}", path, e);
+            return false;
+        }
+    };
+
+    let result = out.write_all(&FILE_BLOB_MAGIC.to_le_bytes())
+        .and_then(|_| out.write_all(&FILE_BLOB_VERSION.to_le_bytes()))
+        .and_then(|_| out.write_all(&(header_bytes.len() as u64).to_le_bytes()))
+        .and_then(|_| out.write_all(&header_bytes))
+        .and_then(|_| out.write_all(&body));
+
+    if let Err(e) = result {
+        warn!("Failed to write file blob cache {:?}: {}", path, e);
+        return false;
+    }
+
+    true
+}
+
+/// Memory-maps `path` and returns every [`CachedFile`] entry whose stored mtime/hash still
+/// matches the file currently on disk, deserialized straight out of the mapping. A missing file,
+/// a stale mtime/hash, or a corrupt individual record drops just that one entry rather than
+/// aborting the whole load, so a partially-stale cache degrades to a partial re-index instead of
+/// none at all.
+pub fn load_file_blob(path: &Path) -> Vec<CachedFile> {
+    let Some(mmap) = open_mmap(path) else { return Vec::new(); };
+
+    if mmap.len() < HEADER_PREFIX_LEN {
+        return Vec::new();
+    }
+
+    let magic = u32::from_le_bytes(mmap[0..4].try_into().unwrap());
+    if magic != FILE_BLOB_MAGIC {
+        warn!("File blob cache {:?} has an invalid magic number", path);
+        return Vec::new();
+    }
+    let version = u32::from_le_bytes(mmap[4..8].try_into().unwrap());
+    if version != FILE_BLOB_VERSION {
+        warn!("File blob cache {:?} version mismatch (got {}, expected {})", path, version, FILE_BLOB_VERSION);
+        return Vec::new();
+    }
+
+    let header_len = u64::from_le_bytes(mmap[8..16].try_into().unwrap()) as usize;
+    let header_start = HEADER_PREFIX_LEN;
+    let Some(header_end) = header_start.checked_add(header_len) else { return Vec::new(); };
+    if header_end > mmap.len() {
+        warn!("File blob cache {:?} header length overruns the file", path);
+        return Vec::new();
+    }
+
+    let header: FileBlobHeader = match bincode::deserialize(&mmap[header_start..header_end]) {
+        Ok(h) => h,
+        Err(e) => {
+            warn!("Failed to parse file blob cache header {:?}: {}", path, e);
+            return Vec::new();
+        }
+    };
+
+    let body_start = header_end;
+    let mut result = Vec::with_capacity(header.entries.len());
+    for entry in &header.entries {
+        let Some(current_mtime) = file_mtime_secs(&entry.path) else { continue };
+        if current_mtime != entry.mtime {
+            continue;
+        }
+        let Some(current_hash) = hash_file_contents(&entry.path) else { continue };
+        if current_hash != entry.hash {
+            continue;
+        }
+
+        let record_start = body_start + entry.offset as usize;
+        let record_end = record_start + entry.length as usize;
+        if record_end > mmap.len() {
+            warn!("File blob cache {:?} has an out-of-bounds record for {}", path, entry.path);
+            continue;
+        }
+
+        match bincode::deserialize::<CachedFile>(&mmap[record_start..record_end]) {
+            Ok(cached_file) => result.push(cached_file),
+            Err(e) => warn!("Failed to deserialize cached file {} from blob store: {}", entry.path, e),
+        }
+    }
+
+    result
+}
+
+fn open_mmap(path: &Path) -> Option<Mmap> {
+    if !path.exists() {
+        return None;
+    }
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!("Failed to open file blob cache {:?}: {}", path, e);
+            return None;
+        }
+    };
+    match unsafe { Mmap::map(&file) } {
+        Ok(m) => Some(m),
+        Err(e) => {
+            warn!("Failed to mmap file blob cache {:?}: {}", path, e);
+            None
+        }
+    }
+}
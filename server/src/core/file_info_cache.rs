@@ -0,0 +1,163 @@
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::io::{BufReader, BufWriter};
+use std::path::PathBuf;
+
+use lsp_types::Diagnostic;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::constants::BuildSteps;
+
+/// Bumped whenever the shape of [`CachedFileInfo`] changes in a way that isn't
+/// backward-compatible, mirroring `persist.rs`'s `CACHE_FORMAT_VERSION` / `cache.rs`'s
+/// `MODULE_CACHE_FORMAT_VERSION`.
+const FILE_INFO_CACHE_FORMAT_VERSION: u32 = 1;
+/// Moderate zstd level: this gets written on every rebuild rather than once at the end of a
+/// batch job, so encode speed matters as much as the ratio.
+const FILE_INFO_CACHE_ZSTD_LEVEL: i32 = 6;
+
+/// The part of a `FileInfo` worth persisting across sessions: its per-build-step diagnostics,
+/// tagged with the exact content hash they were computed from so a file that changed on disk is
+/// never served stale results.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CachedFileInfo {
+    pub text_hash: u64,
+    pub valid: bool,
+    pub diagnostics: HashMap<BuildSteps, Vec<Diagnostic>>,
+}
+
+#[derive(Serialize, Deserialize, Debug)]
+struct FileInfoCacheEnvelope {
+    format_version: u32,
+    server_version: String,
+    entry: CachedFileInfo,
+}
+
+/// Workspace-local, zstd-compressed cache of [`CachedFileInfo`], one file per analyzed source
+/// file. Reopening a large tree reuses validated diagnostics for everything untouched since the
+/// last session instead of re-running SYNTAX→ARCH→ARCH_EVAL→VALIDATION on all of it.
+#[derive(Debug)]
+pub struct FileInfoCacheManager {
+    cache_dir: PathBuf,
+}
+
+impl FileInfoCacheManager {
+    pub fn new() -> Option<Self> {
+        let cache_dir = dirs::data_local_dir()?.join("odoo-ls").join("files");
+        if !cache_dir.exists() {
+            if let Err(e) = fs::create_dir_all(&cache_dir) {
+                warn!("Failed to create file cache directory: {}", e);
+                return None;
+            }
+        }
+        Some(Self { cache_dir })
+    }
+
+    pub fn get_cache_path(&self, uri: &str) -> PathBuf {
+        let hash = format!("{:x}", md5::compute(uri.as_bytes()));
+        self.cache_dir.join(format!("{}.zst", hash))
+    }
+
+    /// Compresses and persists `entry` for `uri`, overwriting whatever was cached before.
+    pub fn save(&self, uri: &str, entry: &CachedFileInfo) -> bool {
+        let cache_path = self.get_cache_path(uri);
+        let file = match File::create(&cache_path) {
+            Ok(f) => f,
+            Err(e) => {
+                warn!("Failed to create file cache {:?}: {}", cache_path, e);
+                return false;
+            }
+        };
+
+        let envelope = FileInfoCacheEnvelope {
+            format_version: FILE_INFO_CACHE_FORMAT_VERSION,
+            server_version: env!("CARGO_PKG_VERSION").to_string(),
+            entry: entry.clone(),
+        };
+
+        let writer = BufWriter::new(file);
+        let mut encoder = match zstd::stream::Encoder::new(writer, FILE_INFO_CACHE_ZSTD_LEVEL) {
+            Ok(e) => e,
+            Err(e) => {
+                warn!("Failed to start compressing file cache for {}: {}", uri, e);
+                return false;
+            }
+        };
+        if let Err(e) = bincode::serialize_into(&mut encoder, &envelope) {
+            warn!("Failed to serialize file cache for {}: {}", uri, e);
+            return false;
+        }
+        if let Err(e) = encoder.finish() {
+            warn!("Failed to finish compressing file cache for {}: {}", uri, e);
+            return false;
+        }
+        true
+    }
+
+    /// Loads the cache for `uri`, discarding it (returning `None`) if it was written by a
+    /// different format/server version, or if it was computed from different content than
+    /// `expected_text_hash` - a mismatch on any of those is treated as a miss, never deserialized
+    /// and trusted blindly.
+    pub fn load(&self, uri: &str, expected_text_hash: u64) -> Option<CachedFileInfo> {
+        let cache_path = self.get_cache_path(uri);
+        if !cache_path.exists() {
+            return None;
+        }
+
+        let file = File::open(&cache_path).ok()?;
+        let reader = BufReader::new(file);
+        let decoder = match zstd::stream::Decoder::new(reader) {
+            Ok(d) => d,
+            Err(e) => {
+                warn!("Failed to decompress file cache for {}: {}", uri, e);
+                return None;
+            }
+        };
+        let envelope: FileInfoCacheEnvelope = match bincode::deserialize_from(decoder) {
+            Ok(e) => e,
+            Err(e) => {
+                warn!("Failed to deserialize file cache for {}: {}", uri, e);
+                return None;
+            }
+        };
+
+        if envelope.format_version != FILE_INFO_CACHE_FORMAT_VERSION {
+            info!("File cache format mismatch for {} (got {}, expected {})", uri, envelope.format_version, FILE_INFO_CACHE_FORMAT_VERSION);
+            return None;
+        }
+        if envelope.server_version != env!("CARGO_PKG_VERSION") {
+            info!("File cache server version mismatch for {} (got {}, expected {})", uri, envelope.server_version, env!("CARGO_PKG_VERSION"));
+            return None;
+        }
+        if envelope.entry.text_hash != expected_text_hash {
+            info!("File cache for {} is stale (content changed since it was cached)", uri);
+            return None;
+        }
+
+        Some(envelope.entry)
+    }
+
+    /// Evicts the cache entry for a single file, e.g. when `FileMgr::delete_path` removes it.
+    pub fn evict(&self, uri: &str) {
+        let cache_path = self.get_cache_path(uri);
+        if cache_path.exists() {
+            if let Err(e) = fs::remove_file(&cache_path) {
+                warn!("Failed to remove file cache {:?}: {}", cache_path, e);
+            }
+        }
+    }
+
+    /// Evicts every cache entry, e.g. when `FileMgr::clear` tears down a workspace.
+    pub fn clear_all(&self) {
+        if let Ok(entries) = fs::read_dir(&self.cache_dir) {
+            for entry in entries.flatten() {
+                let path = entry.path();
+                if path.extension().map_or(false, |ext| ext == "zst") {
+                    let _ = fs::remove_file(&path);
+                }
+            }
+            info!("Cleared all file caches");
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use ruff_python_ast::{ModModule, PySourceType, Stmt};
 use ruff_python_parser::{Parsed, Token, TokenKind};
-use lsp_types::{Diagnostic, DiagnosticSeverity, MessageType, NumberOrString, Position, PublishDiagnosticsParams, Range, TextDocumentContentChangeEvent};
+use lsp_types::{Diagnostic, DiagnosticRelatedInformation, DiagnosticSeverity, Location, MessageType, NumberOrString, Position, PublishDiagnosticsParams, Range, TextDocumentContentChangeEvent};
 use lsp_types::notification::{Notification, PublishDiagnostics};
 use ruff_source_file::{OneIndexed, PositionEncoding, SourceLocation};
 use tracing::{error, warn};
@@ -10,9 +10,13 @@ use std::hash::{Hash, Hasher};
 use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::{atomic::{AtomicBool, Ordering}, Arc, OnceLock};
-use std::{collections::HashMap, fs};
+use std::collections::HashMap;
 use crate::core::config::{DiagnosticFilter, DiagnosticFilterPathType};
 use crate::core::diagnostics::{create_diagnostic, DiagnosticCode, DiagnosticSetting};
+use crate::core::file_info_cache::CachedFileInfo;
+use crate::core::file_operations_interest::FileOperationsInterest;
+use crate::core::indexed_data_file::{parse_csv, parse_xml, IndexedCsv, IndexedXml};
+use crate::core::mmap_loader;
 use crate::core::text_document::TextDocument;
 use crate::features::node_index_ast::IndexedModule;
 use crate::threads::SessionInfo;
@@ -39,6 +43,84 @@ pub enum NoqaInfo {
     Codes(Vec<String>),
 }
 
+/// Per-code diagnostic severity overrides, configurable from the LSP `initializationOptions`
+/// (a `diagnostics` map keyed by code string, e.g. `{"OLS01002": "error"}`) plus a global
+/// `warningsAsErrors` escalation toggle. Every diagnostic is remapped through this, right before
+/// it reaches [`FileInfo::publish_diagnostics`], analogous to rust-analyzer's per-lint config.
+#[derive(Debug, Clone, Default)]
+pub struct DiagnosticsConfig {
+    pub overrides: HashMap<String, DiagnosticSeverityOverride>,
+    pub warnings_as_errors: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiagnosticSeverityOverride {
+    Error,
+    Warning,
+    Information,
+    Hint,
+    /// Drop the diagnostic entirely, regardless of the severity it was emitted with.
+    Off,
+}
+
+impl DiagnosticSeverityOverride {
+    pub fn from_str(value: &str) -> Option<Self> {
+        match value {
+            "error" => Some(DiagnosticSeverityOverride::Error),
+            "warning" => Some(DiagnosticSeverityOverride::Warning),
+            "information" | "info" => Some(DiagnosticSeverityOverride::Information),
+            "hint" => Some(DiagnosticSeverityOverride::Hint),
+            "off" => Some(DiagnosticSeverityOverride::Off),
+            _ => None,
+        }
+    }
+
+    /// Returns `None` for [`DiagnosticSeverityOverride::Off`]: the caller should drop the
+    /// diagnostic instead of publishing it with a remapped severity.
+    pub fn to_lsp_severity(self) -> Option<DiagnosticSeverity> {
+        match self {
+            DiagnosticSeverityOverride::Error => Some(DiagnosticSeverity::ERROR),
+            DiagnosticSeverityOverride::Warning => Some(DiagnosticSeverity::WARNING),
+            DiagnosticSeverityOverride::Information => Some(DiagnosticSeverity::INFORMATION),
+            DiagnosticSeverityOverride::Hint => Some(DiagnosticSeverity::HINT),
+            DiagnosticSeverityOverride::Off => None,
+        }
+    }
+}
+
+/// A secondary span a diagnostic producer wants to point at, expressed as a byte range in
+/// whatever file it lives in rather than an already-resolved `Location`: the target file may be
+/// the current one (a duplicate definition further down) or another one entirely (a symbol
+/// shadowed elsewhere), and its `TextDocument` may not even be loaded yet.
+#[derive(Debug, Clone)]
+pub struct RelatedSpan {
+    pub path: String,
+    pub range: TextRange,
+    pub message: String,
+}
+
+/// Resolves each [`RelatedSpan`] to a real `DiagnosticRelatedInformation`, loading (via
+/// `prepare_ast`) whichever target file isn't already parsed. Spans whose file can't be found or
+/// whose range no longer fits the file (e.g. it changed since the span was recorded) are
+/// dropped rather than failing the whole diagnostic.
+pub fn resolve_related_information(session: &mut SessionInfo, related: &[RelatedSpan]) -> Vec<DiagnosticRelatedInformation> {
+    let mut resolved = Vec::new();
+    for span in related {
+        let file_info = session.sync_odoo.get_file_mgr().borrow().get_file_info(&span.path);
+        let Some(file_info) = file_info else { continue };
+        if file_info.borrow().file_info_ast.borrow().text_document.is_none() {
+            file_info.borrow_mut().prepare_ast(session);
+        }
+        let range = file_info.borrow().try_text_range_to_range(&span.range, session.sync_odoo.encoding);
+        let Some(range) = range else { continue };
+        resolved.push(DiagnosticRelatedInformation {
+            location: Location { uri: FileMgr::pathname2uri(&span.path), range },
+            message: span.message.clone(),
+        });
+    }
+    resolved
+}
+
 pub fn combine_noqa_info(noqas: &Vec<NoqaInfo>) -> NoqaInfo {
     let mut codes = HashSet::new();
     for noqa in noqas.iter() {
@@ -69,6 +151,8 @@ pub struct FileInfoAst {
     pub text_hash: u64,
     pub text_document: Option<TextDocument>,
     pub indexed_module: Option<Arc<IndexedModule>>,
+    pub indexed_xml: Option<IndexedXml>,
+    pub indexed_csv: Option<IndexedCsv>,
     pub ast_type: AstType,
 }
 
@@ -89,7 +173,17 @@ pub struct FileInfo {
     diagnostics: HashMap<BuildSteps, Vec<Diagnostic>>,
     pub noqas_blocs: HashMap<u32, NoqaInfo>,
     noqas_lines: HashMap<u32, NoqaInfo>,
+    /// Byte range of the `# noqa`-style comment token recorded for a given line in `noqas_lines`,
+    /// so a "suppress this diagnostic" code action can replace the existing comment in place
+    /// (merging codes via `combine_noqa_info`) instead of appending a second, invalid comment.
+    noqas_line_ranges: HashMap<u32, TextRange>,
+    /// Hash of the raw bytes last read for an external/unopened file, computed by
+    /// `mmap_loader::load_if_changed_for_path` over the mmap'd (or, on a network filesystem,
+    /// plain-read) content. Lets `update` skip rebuilding the AST entirely when the file on disk
+    /// hasn't changed, without materializing a `String` just to hash it.
+    raw_content_hash: u64,
     diagnostic_filters: Vec<DiagnosticFilter>,
+    diagnostics_config: DiagnosticsConfig,
 
     pub diag_test_comments: Vec<(u32, Vec<String>)>, //for tests: line and list of codes
 }
@@ -106,12 +200,17 @@ impl FileInfo {
                 text_hash: 0,
                 text_document: None,
                 indexed_module: None,
+                indexed_xml: None,
+                indexed_csv: None,
                 ast_type: AstType::Python,
             })),
             diagnostics: HashMap::new(),
             noqas_blocs: HashMap::new(),
             noqas_lines: HashMap::new(),
+            noqas_line_ranges: HashMap::new(),
+            raw_content_hash: 0,
             diagnostic_filters: Vec::new(),
+            diagnostics_config: DiagnosticsConfig::default(),
             diag_test_comments: vec![],
         }
     }
@@ -156,8 +255,23 @@ impl FileInfo {
         } else if is_untitled {
             session.log_message(MessageType::ERROR, format!("Attempt to update untitled file {}, without changes", path));
             return false;
+        } else if is_external && !self.opened {
+            // Unopened dependency files are read far more often than they change: probe the
+            // mmap'd bytes (or a plain read on a network filesystem) and skip rebuilding entirely
+            // when the content hash matches what we already have.
+            match mmap_loader::load_if_changed_for_path(path, self.raw_content_hash) {
+                Ok(None) => return false,
+                Ok(Some((content, hash))) => {
+                    self.raw_content_hash = hash;
+                    self.file_info_ast.borrow_mut().text_document = Some(TextDocument::new(content, self.version.unwrap_or(-1)));
+                },
+                Err(e) => {
+                    session.log_message(MessageType::ERROR, format!("Failed to read file {}, with error {}", path, e));
+                    return false;
+                },
+            }
         } else {
-            match fs::read_to_string(path) {
+            match session.file_system.read_to_string(path) {
                 Ok(content) => {
                     self.file_info_ast.borrow_mut().text_document = Some(TextDocument::new(content, self.version.unwrap_or(-1)));
                 },
@@ -170,21 +284,108 @@ impl FileInfo {
         let mut hasher = DefaultHasher::new();
         self.file_info_ast.borrow_mut().text_document.clone().unwrap().hash(&mut hasher);
         let old_hash = self.file_info_ast.borrow().text_hash;
-        self.file_info_ast.borrow_mut().text_hash = hasher.finish();
-        if old_hash == self.file_info_ast.borrow().text_hash {
+        let new_hash = hasher.finish();
+        self.file_info_ast.borrow_mut().text_hash = new_hash;
+        if old_hash == new_hash {
             return false;
         }
+        // Reopening a workspace re-reads every dependency file before anything has actually
+        // changed: check the persistent cache before paying for a full SYNTAX->ARCH->ARCH_EVAL->
+        // VALIDATION rebuild, and only fall through to `_build_ast` on a miss.
+        if let Some(cached) = session.file_info_cache.as_ref().and_then(|c| c.load(&self.uri, new_hash)) {
+            self.valid = cached.valid;
+            for (step, diagnostics) in cached.diagnostics {
+                self.replace_diagnostics(step, diagnostics);
+            }
+            return true;
+        }
         self._build_ast(session, is_external);
+        if let Some(cache) = session.file_info_cache.as_ref() {
+            cache.save(&self.uri, &CachedFileInfo {
+                text_hash: new_hash,
+                valid: self.valid,
+                diagnostics: self.diagnostics.clone(),
+            });
+        }
         true
     }
 
     pub fn _build_ast(&mut self, session: &mut SessionInfo, is_external: bool) {
         if self.uri.ends_with(".xml") {
             self.file_info_ast.borrow_mut().ast_type = AstType::Xml;
+            let source = S!(self.file_info_ast.borrow().text_document.as_ref().unwrap().contents());
+            if !is_external {
+                self.noqas_blocs.clear();
+                self.noqas_lines.clear();
+                self.noqas_line_ranges.clear();
+            }
+            let (indexed, raw_findings, noqa_lines) = parse_xml(&source);
+            self.file_info_ast.borrow_mut().indexed_xml = Some(indexed);
+            if !is_external {
+                for (line, codes) in noqa_lines {
+                    let info = match codes {
+                        Some(codes) => NoqaInfo::Codes(codes),
+                        None => NoqaInfo::All,
+                    };
+                    self.noqas_lines.insert(line as u32, info);
+                }
+            }
+            self.valid = raw_findings.is_empty();
+            let mut diagnostics = vec![];
+            for finding in raw_findings {
+                let args: Vec<&str> = finding.args.iter().map(String::as_str).collect();
+                if let Some(diagnostic_base) = create_diagnostic(&session, finding.code, &args) {
+                    // Same-file secondary spans (e.g. a duplicate id's first declaration) are
+                    // resolved right here rather than through `resolve_related_information`,
+                    // which fetches the target `FileInfo` from the shared `FileMgr` and would
+                    // try to re-borrow this very file while we're still building it.
+                    let related_information: Vec<DiagnosticRelatedInformation> = finding.related.iter().map(|(range, message)| {
+                        DiagnosticRelatedInformation {
+                            location: Location { uri: FileMgr::pathname2uri(&self.uri), range: self.std_range_to_range(range, session.sync_odoo.encoding) },
+                            message: message.clone(),
+                        }
+                    }).collect();
+                    diagnostics.push(Diagnostic {
+                        range: self.std_range_to_range(&finding.range, session.sync_odoo.encoding),
+                        related_information: if related_information.is_empty() { None } else { Some(related_information) },
+                        ..diagnostic_base
+                    });
+                }
+            }
+            self.replace_diagnostics(BuildSteps::SYNTAX, diagnostics);
             return;
         }
         if self.uri.ends_with(".csv") {
             self.file_info_ast.borrow_mut().ast_type = AstType::Csv;
+            let source = S!(self.file_info_ast.borrow().text_document.as_ref().unwrap().contents());
+            if !is_external {
+                self.noqas_blocs.clear();
+                self.noqas_lines.clear();
+                self.noqas_line_ranges.clear();
+            }
+            let (indexed, raw_findings, noqa_lines) = parse_csv(&source);
+            self.file_info_ast.borrow_mut().indexed_csv = Some(indexed);
+            if !is_external {
+                for (line, codes) in noqa_lines {
+                    let info = match codes {
+                        Some(codes) => NoqaInfo::Codes(codes),
+                        None => NoqaInfo::All,
+                    };
+                    self.noqas_lines.insert(line as u32, info);
+                }
+            }
+            self.valid = raw_findings.is_empty();
+            let mut diagnostics = vec![];
+            for finding in raw_findings {
+                let args: Vec<&str> = finding.args.iter().map(String::as_str).collect();
+                if let Some(diagnostic_base) = create_diagnostic(&session, finding.code, &args) {
+                    diagnostics.push(Diagnostic {
+                        range: self.std_range_to_range(&finding.range, session.sync_odoo.encoding),
+                        ..diagnostic_base
+                    });
+                }
+            }
+            self.replace_diagnostics(BuildSteps::SYNTAX, diagnostics);
             return;
         }
         let mut diagnostics = vec![];
@@ -202,6 +403,7 @@ impl FileInfo {
         if !is_external {
             self.noqas_blocs.clear();
             self.noqas_lines.clear();
+            self.noqas_line_ranges.clear();
             self.extract_tokens(&parsed_module, &source, session.sync_odoo.encoding, session.sync_odoo.test_mode);
         }
         self.valid = true;
@@ -225,7 +427,7 @@ impl FileInfo {
     /* if ast has been set to none to lower memory usage, try to reload it */
     pub fn prepare_ast(&mut self, session: &mut SessionInfo) {
         if self.file_info_ast.borrow_mut().text_document.is_none() { //can already be set in xml files
-            match fs::read_to_string(&self.uri) {
+            match session.file_system.read_to_string(&self.uri) {
                 Ok(content) => {
                     self.file_info_ast.borrow_mut().text_document = Some(TextDocument::new(content, self.version.unwrap_or(-1)));
                 },
@@ -269,7 +471,9 @@ impl FileInfo {
                             if let Some(previous_token) = previous_token {
                                 let prev_location = file_info_ast_ref.text_document.as_ref().unwrap().index().source_location(previous_token.start(), file_info_ast_ref.text_document.as_ref().unwrap().contents(), encoding);
                                 if prev_location.line == source_location.line {
-                                    self.noqas_lines.insert(source_location.line.to_zero_indexed() as u32, noqa_to_add.unwrap());
+                                    let line = source_location.line.to_zero_indexed() as u32;
+                                    self.noqas_lines.insert(line, noqa_to_add.unwrap());
+                                    self.noqas_line_ranges.insert(line, token.range());
                                     noqa_to_add = None;
                                     continue;
                                 }
@@ -344,13 +548,34 @@ impl FileInfo {
         }).collect::<Vec<_>>();
     }
 
+    pub fn update_diagnostics_config(&mut self, session: &SessionInfo) {
+        self.diagnostics_config = session.sync_odoo.config.diagnostics_config.clone();
+    }
+
+    pub fn get_noqa_line(&self, line: u32) -> Option<NoqaInfo> {
+        self.noqas_lines.get(&line).cloned()
+    }
+
+    pub fn get_noqa_line_range(&self, line: u32) -> Option<TextRange> {
+        self.noqas_line_ranges.get(&line).copied()
+    }
+
+    /// Offset right after the last character of `line` (before its line terminator), used to
+    /// append a new trailing comment such as a noqa suppression.
+    pub fn line_end_offset(&self, line: u32, encoding: PositionEncoding) -> Option<u32> {
+        let fia = self.file_info_ast.borrow();
+        let text_document = fia.text_document.as_ref()?;
+        let line_text = text_document.contents().lines().nth(line as usize)?;
+        Some(FileInfo::position_to_offset_with_text_document(text_document, line, line_text.chars().count() as u32, encoding) as u32)
+    }
+
     pub fn publish_diagnostics(&mut self, session: &mut SessionInfo) {
         if self.need_push {
             let mut all_diagnostics = Vec::new();
 
             'diagnostics: for d in self.diagnostics.values().flatten() {
                 //check noqa lines
-                let updated = self.update_range(d.clone(), session.sync_odoo.encoding);
+                let mut updated = self.update_range(d.clone(), session.sync_odoo.encoding);
                 let updated_line = updated.range.start.line;
                 if let Some(noqa_line) = self.noqas_lines.get(&updated_line) {
                     match noqa_line {
@@ -375,6 +600,22 @@ impl FileInfo {
                         }
                     }
                 }
+                //apply per-code severity overrides/suppression from the user's diagnostics config
+                if let Some(code) = &updated.code {
+                    let code_str = match code {
+                        NumberOrString::Number(n) => n.to_string(),
+                        NumberOrString::String(s) => s.clone(),
+                    };
+                    if let Some(severity_override) = self.diagnostics_config.overrides.get(&code_str) {
+                        match severity_override.to_lsp_severity() {
+                            Some(severity) => updated.severity = Some(severity),
+                            None => continue,
+                        }
+                    }
+                }
+                if self.diagnostics_config.warnings_as_errors && updated.severity == Some(DiagnosticSeverity::WARNING) {
+                    updated.severity = Some(DiagnosticSeverity::ERROR);
+                }
                 for filter in self.diagnostic_filters.iter() {
                     if !filter.codes.is_empty(){
                         // we pass the filter if we do not have code, or does it not match the filter
@@ -480,6 +721,7 @@ pub struct FileMgr {
     untitled_files: HashMap<String, Rc<RefCell<FileInfo>>>, // key: untitled URI or unique name
     workspace_folders: HashMap<String, String>,
     has_repeated_workspace_folders: bool,
+    file_operations_interest: FileOperationsInterest,
 }
 
 impl FileMgr {
@@ -490,9 +732,23 @@ impl FileMgr {
             untitled_files: HashMap::new(),
             workspace_folders: HashMap::new(),
             has_repeated_workspace_folders: false,
+            file_operations_interest: FileOperationsInterest::default_interest(),
         }
     }
 
+    /// Whether `path` matches the server's declared interest and should be tracked at all.
+    /// `update_file_info` keeps updating a path that is already tracked regardless of this
+    /// result; `delete_path` and `rename_path` only touch tracked paths that match it.
+    pub fn matches_interest(&self, path: &str) -> bool {
+        self.file_operations_interest.matches(path)
+    }
+
+    /// The interest filter's include globs, so `workspace/willRenameFiles`/`didCreateFiles`/
+    /// `didDeleteFiles` registration can be scoped to paths the server will actually react to.
+    pub fn file_operations_interest(&self) -> &FileOperationsInterest {
+        &self.file_operations_interest
+    }
+
     #[allow(non_snake_case)]
     pub fn textRange_to_temporary_Range(range: &TextRange) -> Range {
         Range::new(
@@ -526,7 +782,7 @@ impl FileMgr {
             return Range::default();
         }
         //file not in cache, let's load text_document on the fly
-        match fs::read_to_string(path) {
+        match session.file_system.read_to_string(path) {
             Ok(content) => {
                 let text_document = TextDocument::new(content, -1);
                 return Range {
@@ -558,7 +814,7 @@ impl FileMgr {
             return Range::default();
         }
         //file not in cache, let's load text_document on the fly
-        match fs::read_to_string(path) {
+        match session.file_system.read_to_string(path) {
             Ok(content) => {
                 let text_document = TextDocument::new(content, -1);
                 return Range {
@@ -577,8 +833,16 @@ impl FileMgr {
         path.starts_with("untitled:")
     }
 
-    pub fn update_file_info(&mut self, session: &mut SessionInfo, uri: &str, content: Option<&Vec<TextDocumentContentChangeEvent>>, version: Option<i32>, force: bool) -> (bool, Rc<RefCell<FileInfo>>) {
+    /// Returns `None` without creating anything when `uri` is neither already tracked nor of
+    /// interest to the server (see [`FileMgr::matches_interest`]) - e.g. a lockfile or `.pyc` the
+    /// client notified us about despite our registered glob filter. An untitled (in-memory,
+    /// unsaved) buffer has no real path to match against a glob, so it's always tracked.
+    pub fn update_file_info(&mut self, session: &mut SessionInfo, uri: &str, content: Option<&Vec<TextDocumentContentChangeEvent>>, version: Option<i32>, force: bool) -> Option<(bool, Rc<RefCell<FileInfo>>)> {
         let is_untitled = Self::is_untitled(uri);
+        let already_tracked = if is_untitled { self.untitled_files.contains_key(uri) } else { self.files.contains_key(uri) };
+        if !already_tracked && !is_untitled && !self.matches_interest(uri) {
+            return None;
+        }
         let entry = if is_untitled {
             self.untitled_files.entry(uri.to_string())
         } else {
@@ -587,6 +851,7 @@ impl FileMgr {
         let file_info = entry.or_insert_with(|| {
             let mut file_info = FileInfo::new(uri.to_string());
             file_info.update_diagnostic_filters(session);
+            file_info.update_diagnostics_config(session);
             Rc::new(RefCell::new(file_info))
         });
         let return_info = file_info.clone();
@@ -600,7 +865,7 @@ impl FileMgr {
             updated = file_info_mut.update(session, uri, content, version, !is_part_of_ep, force, is_untitled);
             drop(file_info_mut);
         }
-        (updated, return_info)
+        Some((updated, return_info))
     }
 
     pub fn update_all_file_diagnostic_filters(&mut self, session: &SessionInfo) {
@@ -609,9 +874,20 @@ impl FileMgr {
         }
     }
 
+    pub fn update_all_file_diagnostics_config(&mut self, session: &SessionInfo) {
+        for file_info in self.files.values() {
+            file_info.borrow_mut().update_diagnostics_config(session);
+        }
+    }
+
     pub fn delete_path(session: &mut SessionInfo, uri: &String) {
-        //delete all files that are the uri or in subdirectory
-        let matching_keys: Vec<String> = session.sync_odoo.get_file_mgr().borrow_mut().files.keys().filter(|k| PathBuf::from(k).starts_with(uri)).cloned().collect();
+        //delete all files that are the uri or in subdirectory, skipping any that fell outside the
+        //interest filter (a directory delete can still sweep up paths we never cared about)
+        let matching_keys: Vec<String> = {
+            let file_mgr = session.sync_odoo.get_file_mgr();
+            let file_mgr = file_mgr.borrow();
+            file_mgr.files.keys().filter(|k| PathBuf::from(k).starts_with(uri) && file_mgr.matches_interest(k)).cloned().collect()
+        };
         for key in matching_keys {
             let to_del = session.sync_odoo.get_file_mgr().borrow_mut().files.remove(&key);
             if let Some(to_del) = to_del {
@@ -624,6 +900,60 @@ impl FileMgr {
                     to_del.publish_diagnostics(session)
                 }
             }
+            if let Some(cache) = session.file_info_cache.as_ref() {
+                cache.evict(&key);
+            }
+            crate::utils::invalidate_case_cache(&key);
+            crate::core::symbol_index::evict_modules_touching(&key);
+        }
+    }
+
+    /// Moves `old_uri` (and, for a directory rename, every file nested under it) to `new_uri`,
+    /// keeping the same `Rc<RefCell<FileInfo>>` - and therefore its parsed AST, diagnostics and
+    /// noqa state - instead of tearing it down and reparsing like a delete+create would. Clears
+    /// the old URI's diagnostics client-side exactly as `delete_path` does, then republishes the
+    /// same diagnostics under the new URI so nothing is lost mid-rename.
+    pub fn rename_path(session: &mut SessionInfo, old_uri: &String, new_uri: &String) {
+        let matching_keys: Vec<String> = {
+            let file_mgr = session.sync_odoo.get_file_mgr();
+            let file_mgr = file_mgr.borrow();
+            file_mgr.files.keys().filter(|k| PathBuf::from(k).starts_with(old_uri) && file_mgr.matches_interest(k)).cloned().collect()
+        };
+        for old_key in matching_keys {
+            let new_key = format!("{}{}", new_uri, &old_key[old_uri.len()..]);
+            let file_info = session.sync_odoo.get_file_mgr().borrow_mut().files.remove(&old_key);
+            let Some(file_info) = file_info else { continue };
+            if SyncOdoo::is_in_workspace_or_entry(session, &old_key) {
+                session.send_notification::<PublishDiagnosticsParams>(PublishDiagnostics::METHOD, PublishDiagnosticsParams {
+                    uri: FileMgr::pathname2uri(&old_key),
+                    diagnostics: vec![],
+                    version: file_info.borrow().version,
+                });
+            }
+            {
+                let mut fi = file_info.borrow_mut();
+                fi.uri = new_key.clone();
+                fi.need_push = true;
+            }
+            if SyncOdoo::is_in_workspace_or_entry(session, &new_key) {
+                file_info.borrow_mut().update_diagnostic_filters(session);
+                file_info.borrow_mut().update_diagnostics_config(session);
+                file_info.borrow_mut().publish_diagnostics(session);
+            }
+            crate::utils::invalidate_case_cache(&old_key);
+            crate::utils::invalidate_case_cache(&new_key);
+            crate::core::symbol_index::evict_modules_touching(&old_key);
+            crate::core::symbol_index::evict_modules_touching(&new_key);
+            session.sync_odoo.get_file_mgr().borrow_mut().files.insert(new_key, file_info);
+        }
+    }
+
+    /// Batch form of [`FileMgr::rename_path`] for the `workspace/didRenameFiles` notification,
+    /// which carries every renamed pair from a single client-side operation (e.g. a multi-file
+    /// drag-and-drop) together.
+    pub fn rename_paths(session: &mut SessionInfo, pairs: &[(String, String)]) {
+        for (old_uri, new_uri) in pairs {
+            FileMgr::rename_path(session, old_uri, new_uri);
         }
     }
 
@@ -654,6 +984,9 @@ impl FileMgr {
         }
         drop(file_mgr);
         session.sync_odoo.get_file_mgr().borrow_mut().files.clear();
+        if let Some(cache) = session.file_info_cache.as_ref() {
+            cache.clear_all();
+        }
     }
 
     pub fn add_workspace_folder(&mut self, name: String, path: String) {
@@ -0,0 +1,52 @@
+use glob::Pattern;
+
+/// Compiled glob rules gating which filesystem paths `FileMgr` actually cares about. Built once
+/// (from the server's configuration, falling back to [`FileOperationsInterest::default_interest`])
+/// and consulted before creating, renaming or deleting a cached `FileInfo`, so a lockfile, a
+/// `.pyc`, or anything under a `migrations` folder never gets instantiated and diagnosed just
+/// because the client happened to notify us about it.
+#[derive(Debug, Clone)]
+pub struct FileOperationsInterest {
+    includes: Vec<Pattern>,
+    excludes: Vec<Pattern>,
+}
+
+impl FileOperationsInterest {
+    pub fn new(includes: &[&str], excludes: &[&str]) -> Self {
+        Self {
+            includes: includes.iter().filter_map(|p| Pattern::new(p).ok()).collect(),
+            excludes: excludes.iter().filter_map(|p| Pattern::new(p).ok()).collect(),
+        }
+    }
+
+    /// The interest used until the server negotiates something more specific with the client:
+    /// Python sources, manifests and the data file types `FileInfo::_build_ast` already knows how
+    /// to parse, excluding migration scripts and bytecode caches.
+    pub fn default_interest() -> Self {
+        Self::new(
+            &["**/*.py", "**/*.xml", "**/*.csv", "**/__manifest__.py"],
+            &["**/migrations/**", "**/__pycache__/**", "**/*.pyc"],
+        )
+    }
+
+    /// Whether `path` should be tracked: it must match at least one include glob and none of the
+    /// exclude globs.
+    pub fn matches(&self, path: &str) -> bool {
+        if self.excludes.iter().any(|p| p.matches(path)) {
+            return false;
+        }
+        self.includes.iter().any(|p| p.matches(path))
+    }
+
+    /// The include globs, for scoping a client's `didCreate`/`willRename`/`didDelete`
+    /// notification registration to paths we'd actually act on.
+    pub fn include_globs(&self) -> Vec<String> {
+        self.includes.iter().map(|p| p.as_str().to_string()).collect()
+    }
+}
+
+impl Default for FileOperationsInterest {
+    fn default() -> Self {
+        Self::default_interest()
+    }
+}
@@ -0,0 +1,99 @@
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
+use std::sync::{Arc, RwLock};
+
+/// Cheap, cloneable stand-in for `std::fs::Metadata` - just what callers in this crate actually
+/// need, so a fake [`FileSystem`] implementation doesn't have to fabricate a real OS-backed
+/// `Metadata` (which has no public constructor).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileMetadata {
+    pub len: u64,
+    pub is_dir: bool,
+}
+
+/// Abstracts "read a file that isn't in our in-memory cache yet" away from `std::fs`. Every
+/// on-the-fly load in `FileMgr` (the fallback in `text_range_to_range`/`std_range_to_range`,
+/// `prepare_ast`, `FileInfo::update`) goes through this instead of calling `fs::read_to_string`
+/// directly - except `FileInfo::update` on an external, unopened file, which reads it through
+/// `mmap_loader` instead - so a remote/WSL/SSH workspace can answer from wherever the file actually lives, and
+/// tests can serve deterministic fixtures without touching the real disk.
+pub trait FileSystem: std::fmt::Debug {
+    fn read_to_string(&self, path: &str) -> io::Result<String>;
+    fn exists(&self, path: &str) -> bool;
+    fn metadata(&self, path: &str) -> io::Result<FileMetadata>;
+}
+
+/// The default implementation, reading straight from the local disk.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct LocalFs;
+
+impl FileSystem for LocalFs {
+    fn read_to_string(&self, path: &str) -> io::Result<String> {
+        std::fs::read_to_string(path)
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        Path::new(path).exists()
+    }
+
+    fn metadata(&self, path: &str) -> io::Result<FileMetadata> {
+        let meta = std::fs::metadata(path)?;
+        Ok(FileMetadata { len: meta.len(), is_dir: meta.is_dir() })
+    }
+}
+
+pub fn local_fs() -> Arc<dyn FileSystem> {
+    Arc::new(LocalFs)
+}
+
+/// An all-in-memory [`FileSystem`], keyed by the same path strings the real one would use.
+/// Lets tests build an entry point out of string sources without writing anything under
+/// `tests/data`, and lets an editor overlay unsaved buffer contents over disk without touching
+/// the real filesystem. `len`/`is_dir` in [`Self::metadata`] are derived from the stored content
+/// (files only - this has no notion of directories), which is enough for every current caller.
+#[derive(Debug, Default)]
+pub struct InMemoryFs {
+    files: RwLock<HashMap<String, String>>,
+}
+
+impl InMemoryFs {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_files(files: HashMap<String, String>) -> Self {
+        Self { files: RwLock::new(files) }
+    }
+
+    /// Inserts or overwrites `path`'s content - how an editor's overlay would push an unsaved
+    /// buffer, or how a test seeds a fixture, without going through `FileMgr`'s did-open path.
+    pub fn set_file(&self, path: &str, content: String) {
+        self.files.write().unwrap().insert(path.to_string(), content);
+    }
+
+    pub fn remove_file(&self, path: &str) {
+        self.files.write().unwrap().remove(path);
+    }
+}
+
+impl FileSystem for InMemoryFs {
+    fn read_to_string(&self, path: &str) -> io::Result<String> {
+        self.files.read().unwrap().get(path).cloned()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no in-memory file at {}", path)))
+    }
+
+    fn exists(&self, path: &str) -> bool {
+        self.files.read().unwrap().contains_key(path)
+    }
+
+    fn metadata(&self, path: &str) -> io::Result<FileMetadata> {
+        self.files.read().unwrap().get(path)
+            .map(|content| FileMetadata { len: content.len() as u64, is_dir: false })
+            .ok_or_else(|| io::Error::new(io::ErrorKind::NotFound, format!("no in-memory file at {}", path)))
+    }
+}
+
+pub fn in_memory_fs() -> Arc<InMemoryFs> {
+    Arc::new(InMemoryFs::new())
+}
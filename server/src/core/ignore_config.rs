@@ -0,0 +1,62 @@
+use glob::Pattern;
+
+/// Glob-based ignore layer consulted by [`crate::core::cache::collect_files_recursively`] before
+/// a `CachedFile` is created, so vendored/generated trees don't get pulled into the symbol cache
+/// just because they happen to contain file/package symbols. Distinct from
+/// [`crate::core::file_operations_interest::FileOperationsInterest`], which gates which paths
+/// `FileMgr` tracks in the first place - this is a second, cache-specific filter for trees that
+/// are tracked but still shouldn't be persisted into the cache.
+#[derive(Debug, Clone)]
+pub struct IgnoreConfig {
+    excludes: Vec<Pattern>,
+    skip_binary: bool,
+}
+
+impl IgnoreConfig {
+    pub fn new(excludes: &[&str], skip_binary: bool) -> Self {
+        Self {
+            excludes: excludes.iter().filter_map(|p| Pattern::new(p).ok()).collect(),
+            skip_binary,
+        }
+    }
+
+    /// The rules used until the user configures their own: version control metadata, vendored
+    /// JS dependencies, and Python's own bytecode cache, plus binary-content sniffing.
+    pub fn default_ignore() -> Self {
+        Self::new(&["**/.git/**", "**/node_modules/**", "**/__pycache__/**"], true)
+    }
+
+    /// Whether `path` should be excluded from the cache, either because it matches one of the
+    /// configured glob patterns or (when `skip_binary` is set) because its first kilobyte looks
+    /// like binary content rather than text.
+    pub fn is_ignored(&self, path: &str) -> bool {
+        self.matching_glob(path).is_some() || (self.skip_binary && looks_binary(path))
+    }
+
+    /// The configured glob pattern `path` matches, if any - surfaced so callers can report why a
+    /// given path was excluded from the index instead of just silently dropping it.
+    pub fn matching_glob(&self, path: &str) -> Option<&str> {
+        self.excludes.iter().find(|p| p.matches(path)).map(|p| p.as_str())
+    }
+
+    /// The effective ignore globs, for diagnostics/logging.
+    pub fn ignore_globs(&self) -> Vec<String> {
+        self.excludes.iter().map(|p| p.as_str().to_string()).collect()
+    }
+}
+
+impl Default for IgnoreConfig {
+    fn default() -> Self {
+        Self::default_ignore()
+    }
+}
+
+/// Samples the first kilobyte of `path` and treats the presence of a NUL byte as evidence the
+/// file is binary - the same cheap heuristic `file`/git use, no need to decode the whole file.
+fn looks_binary(path: &str) -> bool {
+    use std::io::Read;
+    let Ok(mut file) = std::fs::File::open(path) else { return false };
+    let mut buf = [0u8; 1024];
+    let Ok(n) = file.read(&mut buf) else { return false };
+    buf[..n].contains(&0)
+}
@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+
+use crate::core::diagnostics::DiagnosticCode;
+
+/// Which of the four element kinds Odoo XML data files are built from this index tracks. Not a
+/// full DOM - just enough structure to support completion/diagnostics without re-parsing with
+/// `roxmltree` on every request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum XmlElementKind {
+    Record,
+    Field,
+    Template,
+    Menuitem,
+}
+
+#[derive(Debug, Clone)]
+pub struct XmlElementInfo {
+    pub kind: XmlElementKind,
+    pub id: Option<String>,
+    pub range: std::ops::Range<usize>,
+}
+
+/// Lightweight index of the `<record>`/`<field>`/`<template>`/`<menuitem>` elements of an XML
+/// data file, built alongside `IndexedModule` for Python files (but far shallower).
+#[derive(Debug, Default)]
+pub struct IndexedXml {
+    pub elements: Vec<XmlElementInfo>,
+}
+
+/// A SYNTAX-step finding from `parse_xml`/`parse_csv`, reported as a byte range so the caller
+/// (which owns the `TextDocument` needed for `std_range_to_range`) can convert it to an LSP
+/// `Range` and attach it to a real `Diagnostic` via `create_diagnostic`.
+pub struct RawFinding {
+    pub code: DiagnosticCode,
+    pub args: Vec<String>,
+    pub range: std::ops::Range<usize>,
+    /// Secondary spans in the same file worth pointing at alongside the primary range (e.g. the
+    /// earlier declaration a duplicate id conflicts with).
+    pub related: Vec<(std::ops::Range<usize>, String)>,
+}
+
+/// Parses an Odoo XML data file into an [`IndexedXml`], flagging malformed XML and duplicate
+/// `id` attributes on `<record>`/`<menuitem>` elements (Odoo requires those ids to be unique
+/// within a module). A `<!-- odools: noqa -->` comment anywhere on a line is reported back as a
+/// `(line, codes)` pair so the caller can feed it through the same `noqas_lines`/`noqas_blocs`
+/// suppression path used for Python files.
+pub fn parse_xml(source: &str) -> (IndexedXml, Vec<RawFinding>, Vec<(usize, Option<Vec<String>>)>) {
+    let mut indexed = IndexedXml::default();
+    let mut findings = Vec::new();
+    let mut noqa_lines = Vec::new();
+
+    for (line_no, line) in source.lines().enumerate() {
+        if let Some(pos) = line.find("<!--") {
+            let comment = &line[pos..];
+            if let Some(rest) = comment.strip_prefix("<!--").map(|s| s.trim_end_matches("-->").trim()) {
+                if let Some(after) = rest.strip_prefix("odools:").map(str::trim).and_then(|s| s.strip_prefix("noqa")) {
+                    let codes: Vec<String> = after.split(|c: char| c == ',' || c.is_whitespace() || c == ':')
+                        .map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect();
+                    noqa_lines.push((line_no, if codes.is_empty() { None } else { Some(codes) }));
+                }
+            }
+        }
+    }
+
+    let opt = roxmltree::ParsingOptions { allow_dtd: true, ..roxmltree::ParsingOptions::default() };
+    let doc = match roxmltree::Document::parse_with_options(source, opt) {
+        Ok(doc) => doc,
+        Err(e) => {
+            findings.push(RawFinding { code: DiagnosticCode::OLS05001, args: vec![e.to_string()], range: 0..0, related: vec![] });
+            return (indexed, findings, noqa_lines);
+        }
+    };
+
+    let mut seen_ids: HashMap<String, std::ops::Range<usize>> = HashMap::new();
+    for node in doc.descendants().filter(|n| n.is_element()) {
+        let kind = match node.tag_name().name() {
+            "record" => XmlElementKind::Record,
+            "field" => XmlElementKind::Field,
+            "template" => XmlElementKind::Template,
+            "menuitem" => XmlElementKind::Menuitem,
+            _ => continue,
+        };
+        let id = node.attribute("id").map(str::to_string);
+        let range = node.range();
+
+        if matches!(kind, XmlElementKind::Record | XmlElementKind::Menuitem) {
+            if let Some(id_value) = &id {
+                if let Some(previous_range) = seen_ids.insert(id_value.clone(), range.clone()) {
+                    findings.push(RawFinding {
+                        code: DiagnosticCode::OLS05002,
+                        args: vec![id_value.clone()],
+                        range: range.clone(),
+                        related: vec![(previous_range, format!("First declared here as `{}`", id_value))],
+                    });
+                }
+            }
+        }
+
+        indexed.elements.push(XmlElementInfo { kind, id, range });
+    }
+
+    (indexed, findings, noqa_lines)
+}
+
+/// A single data row of a CSV data file (e.g. `ir.model.access.csv`), kept alongside its byte
+/// range for diagnostics.
+#[derive(Debug, Clone)]
+pub struct CsvRecord {
+    pub fields: Vec<String>,
+    pub range: std::ops::Range<usize>,
+}
+
+#[derive(Debug, Default)]
+pub struct IndexedCsv {
+    pub header: Vec<String>,
+    /// Byte range of the header row, kept separately from `header` (a plain `Vec<String>`) so a
+    /// cursor landing on a column name can still be mapped back to a specific cell.
+    pub header_range: Option<std::ops::Range<usize>>,
+    pub records: Vec<CsvRecord>,
+}
+
+/// Parses an Odoo CSV data file, flagging rows whose column count doesn't match the header.
+/// Validating that a `model_id:id` column actually references a known model would require the
+/// model registry (`SyncOdoo`/`ModelData`), which this module doesn't have access to - left for
+/// the caller to layer on top once it has a session to resolve models against.
+pub fn parse_csv(source: &str) -> (IndexedCsv, Vec<RawFinding>, Vec<(usize, Option<Vec<String>>)>) {
+    let mut indexed = IndexedCsv::default();
+    let mut findings = Vec::new();
+    let mut noqa_lines = Vec::new();
+    let mut offset = 0usize;
+
+    for (line_no, line) in source.lines().enumerate() {
+        let line_range = offset..offset + line.len();
+        offset += line.len() + 1;
+
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix('#') {
+            if let Some(after) = rest.trim().strip_prefix("odools:").map(str::trim).and_then(|s| s.strip_prefix("noqa")) {
+                let codes: Vec<String> = after.split(|c: char| c == ',' || c.is_whitespace() || c == ':')
+                    .map(str::trim).filter(|s| !s.is_empty()).map(str::to_string).collect();
+                noqa_lines.push((line_no, if codes.is_empty() { None } else { Some(codes) }));
+            }
+            continue;
+        }
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        let fields: Vec<String> = line.split(',').map(str::to_string).collect();
+        if indexed.header.is_empty() {
+            indexed.header = fields;
+            indexed.header_range = Some(line_range);
+            continue;
+        }
+
+        if fields.len() != indexed.header.len() {
+            findings.push(RawFinding {
+                code: DiagnosticCode::OLS06001,
+                args: vec![fields.len().to_string(), indexed.header.len().to_string()],
+                range: line_range.clone(),
+                related: vec![],
+            });
+        }
+        indexed.records.push(CsvRecord { fields, range: line_range });
+    }
+
+    (indexed, findings, noqa_lines)
+}
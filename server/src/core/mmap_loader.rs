@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::collections::hash_map::DefaultHasher;
+use std::io;
+use std::path::Path;
+use std::sync::{Mutex, OnceLock};
+
+/// Caches whether a given workspace folder's filesystem is network-backed, so the `statfs`/UNC
+/// probe only runs once per folder instead of on every unopened-file load.
+fn network_fs_cache() -> &'static Mutex<HashMap<String, bool>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, bool>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns whether `folder` lives on a network filesystem (NFS on Linux, a UNC share on
+/// Windows), caching the result. mmap over NFS can silently truncate reads or SIGBUS on
+/// truncation mid-read, so callers must fall back to `fs::read_to_string` when this is `true`.
+pub fn is_network_filesystem(folder: &str) -> bool {
+    if let Some(cached) = network_fs_cache().lock().unwrap().get(folder) {
+        return *cached;
+    }
+    let detected = probe_network_filesystem(folder);
+    network_fs_cache().lock().unwrap().insert(folder.to_string(), detected);
+    detected
+}
+
+#[cfg(target_os = "linux")]
+fn probe_network_filesystem(folder: &str) -> bool {
+    const NFS_SUPER_MAGIC: i64 = 0x6969;
+    const CIFS_MAGIC_NUMBER: i64 = 0xFF534D42u32 as i64;
+
+    let Ok(c_path) = std::ffi::CString::new(folder) else { return false };
+    unsafe {
+        let mut stats: libc::statfs = std::mem::zeroed();
+        if libc::statfs(c_path.as_ptr(), &mut stats) != 0 {
+            return false;
+        }
+        let f_type = stats.f_type as i64;
+        f_type == NFS_SUPER_MAGIC || f_type == CIFS_MAGIC_NUMBER
+    }
+}
+
+#[cfg(target_os = "windows")]
+fn probe_network_filesystem(folder: &str) -> bool {
+    // UNC paths (`\\server\share\...`) are always network shares; reparse points can also hide a
+    // mapped network drive behind a local-looking path, which `legacy_unc_paths()` already tracks
+    // as a compatibility signal for this same class of problem.
+    folder.starts_with(r"\\") || crate::core::file_mgr::legacy_unc_paths().load(std::sync::atomic::Ordering::Relaxed)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "windows")))]
+fn probe_network_filesystem(_folder: &str) -> bool {
+    false
+}
+
+/// Loads an unopened/external file for change detection, preferring an mmap over the raw bytes
+/// (no heap copy) unless the file lives on a network filesystem, in which case it falls back to
+/// `fs::read_to_string`. Returns `None` when the content hash matches `previous_hash`, so the
+/// caller can skip rebuilding the AST entirely for files that haven't changed on disk.
+pub fn load_if_changed(path: &str, is_network_fs: bool, previous_hash: u64) -> io::Result<Option<(String, u64)>> {
+    if is_network_fs {
+        let content = fs::read_to_string(path)?;
+        let hash = hash_bytes(content.as_bytes());
+        if hash == previous_hash {
+            return Ok(None);
+        }
+        return Ok(Some((content, hash)));
+    }
+
+    let file = fs::File::open(path)?;
+    let mmap = unsafe { memmap2::Mmap::map(&file)? };
+    let hash = hash_bytes(&mmap);
+    if hash == previous_hash {
+        return Ok(None);
+    }
+    let content = String::from_utf8_lossy(&mmap).into_owned();
+    Ok(Some((content, hash)))
+}
+
+fn hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Convenience wrapper that resolves the network-filesystem flag for `path`'s parent folder
+/// before loading. Prefer [`load_if_changed`] directly when the caller already knows the answer
+/// for the enclosing workspace folder, to avoid repeating the cache lookup per file.
+pub fn load_if_changed_for_path(path: &str, previous_hash: u64) -> io::Result<Option<(String, u64)>> {
+    let folder = Path::new(path).parent().and_then(|p| p.to_str()).unwrap_or(path);
+    let is_network_fs = is_network_filesystem(folder);
+    load_if_changed(path, is_network_fs, previous_hash)
+}
@@ -1,6 +1,7 @@
 use serde::{Serialize, Deserialize};
 use ruff_text_size::TextRange;
 use std::collections::{HashMap, HashSet};
+use std::io::{Read, Write};
 use std::rc::Rc;
 use std::cell::RefCell;
 use crate::constants::OYarn;
@@ -12,6 +13,46 @@ use crate::core::symbols::variable_symbol::VariableSymbol;
 use crate::core::model::{Model, ModelData};
 use crate::oyarn;
 use crate::threads::SessionInfo;
+use crate::S;
+
+/// Magic number written at the start of every on-disk symbol cache file, so a truncated or
+/// foreign file is reported as corrupt instead of producing a confusing bincode error.
+const CACHE_MAGIC: u32 = 0x4F4C_5343; // "OLSC"
+/// Bumped whenever the binary layout of [`CachedSymbol`] (or anything it contains) changes
+/// in a way that is not backward-compatible.
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// Errors that can occur while reading or writing a symbol cache file.
+///
+/// Every variant is meant to be handled the same way by callers: log it and fall back to
+/// rebuilding the symbol from source, rather than treating it as fatal.
+#[derive(Debug)]
+pub enum CacheError {
+    Io(std::io::Error),
+    /// The file doesn't start with our magic number, ended before a required field could be read,
+    /// or was written by a different server version.
+    Corrupt(String),
+    /// The cache was written by a different format version and cannot be trusted.
+    VersionMismatch { found: u32, expected: u32 },
+}
+
+impl std::fmt::Display for CacheError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheError::Io(e) => write!(f, "cache I/O error: {}", e),
+            CacheError::Corrupt(msg) => write!(f, "corrupt cache file: {}", msg),
+            CacheError::VersionMismatch { found, expected } => write!(f, "cache format version mismatch (found {}, expected {})", found, expected),
+        }
+    }
+}
+
+impl std::error::Error for CacheError {}
+
+impl From<std::io::Error> for CacheError {
+    fn from(e: std::io::Error) -> Self {
+        CacheError::Io(e)
+    }
+}
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum CachedSymbol {
@@ -120,17 +161,66 @@ impl CachedSymbol {
             std::fs::create_dir_all(parent)?;
         }
         let file = std::fs::File::create(cache_path)?;
-        let writer = std::io::BufWriter::new(file);
+        let mut writer = std::io::BufWriter::new(file);
+        writer.write_all(&CACHE_MAGIC.to_le_bytes())?;
+        writer.write_all(&CACHE_FORMAT_VERSION.to_le_bytes())?;
+        let server_version = env!("CARGO_PKG_VERSION").as_bytes();
+        writer.write_all(&(server_version.len() as u32).to_le_bytes())?;
+        writer.write_all(server_version)?;
         bincode::serialize_into(writer, self)?;
         Ok(())
     }
 
-    pub fn load_from_disk(cache_path: &std::path::Path) -> Result<Self, Box<dyn std::error::Error>> {
+    /// Load a cached symbol from disk, checking the header (magic, format version, server
+    /// version) before trusting the bincode payload.
+    pub fn load_from_disk(cache_path: &std::path::Path) -> Result<Self, CacheError> {
         let file = std::fs::File::open(cache_path)?;
-        let reader = std::io::BufReader::new(file);
-        let cached = bincode::deserialize_from(reader)?;
+        let mut reader = std::io::BufReader::new(file);
+        Self::read_header(&mut reader)?;
+        bincode::deserialize_from(reader).map_err(|e| CacheError::Corrupt(e.to_string()))
+    }
+
+    /// Like [`Self::load_from_disk`], but additionally rejects the cache if it was computed from
+    /// a different version of the file's text (tracked by [`CachedFile::processed_text_hash`]).
+    pub fn load_from_disk_checked(cache_path: &std::path::Path, expected_text_hash: u64) -> Result<Self, CacheError> {
+        let cached = Self::load_from_disk(cache_path)?;
+        if let CachedSymbol::File(f) = &cached {
+            if f.processed_text_hash != expected_text_hash {
+                return Err(CacheError::Corrupt(format!(
+                    "processed_text_hash mismatch (cache has {}, file has {})",
+                    f.processed_text_hash, expected_text_hash
+                )));
+            }
+        }
         Ok(cached)
     }
+
+    fn read_header<R: Read>(reader: &mut R) -> Result<(), CacheError> {
+        let mut u32_buf = [0u8; 4];
+
+        reader.read_exact(&mut u32_buf).map_err(|_| CacheError::Corrupt(S!("truncated header: missing magic")))?;
+        let magic = u32::from_le_bytes(u32_buf);
+        if magic != CACHE_MAGIC {
+            return Err(CacheError::Corrupt(format!("unexpected magic number {:#x}", magic)));
+        }
+
+        reader.read_exact(&mut u32_buf).map_err(|_| CacheError::Corrupt(S!("truncated header: missing format version")))?;
+        let format_version = u32::from_le_bytes(u32_buf);
+        if format_version != CACHE_FORMAT_VERSION {
+            return Err(CacheError::VersionMismatch { found: format_version, expected: CACHE_FORMAT_VERSION });
+        }
+
+        reader.read_exact(&mut u32_buf).map_err(|_| CacheError::Corrupt(S!("truncated header: missing server version length")))?;
+        let version_len = u32::from_le_bytes(u32_buf) as usize;
+        let mut version_buf = vec![0u8; version_len];
+        reader.read_exact(&mut version_buf).map_err(|_| CacheError::Corrupt(S!("truncated header: missing server version")))?;
+        let server_version = String::from_utf8(version_buf).map_err(|_| CacheError::Corrupt(S!("server version is not valid utf-8")))?;
+        if server_version != env!("CARGO_PKG_VERSION") {
+            return Err(CacheError::Corrupt(format!("cache was written by server version {}, expected {}", server_version, env!("CARGO_PKG_VERSION"))));
+        }
+
+        Ok(())
+    }
 }
 
 impl CachedFile {
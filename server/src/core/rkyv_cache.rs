@@ -0,0 +1,139 @@
+//! Zero-copy alternative to `cache.rs`'s bincode module cache, gated behind the `rkyv-cache`
+//! feature for migration: instead of `bincode::deserialize_from` eagerly rebuilding the whole
+//! `CachedModule` tree on every startup, the on-disk file is `mmap`'d and read back as an
+//! `&ArchivedCachedModule` directly over the mapped bytes, so large Odoo installs with thousands
+//! of cached files don't pay to materialize symbols that end up unused this session.
+#![cfg(feature = "rkyv-cache")]
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::Path;
+
+use memmap2::Mmap;
+use ouroboros::self_referencing;
+use tracing::warn;
+
+use crate::core::cache::{ArchivedCachedModule, CachedModule};
+
+/// Magic number at the start of an rkyv-backed module cache file, distinct from the bincode
+/// envelope's own framing so the two formats are never confused with each other.
+const RKYV_CACHE_MAGIC: u32 = 0x4F4C_5352; // "OLSR"
+/// Bumped whenever `CachedModule`'s archived layout changes in a way older archives can't be
+/// read back from, so a cache built by a different rkyv schema is discarded wholesale.
+const RKYV_CACHE_VERSION: u32 = 1;
+const HEADER_LEN: usize = 4 + 4;
+
+/// Owns the memory map a module's archived cache lives in, alongside the `&'this
+/// ArchivedCachedModule` borrowed from it - the mmap must outlive every archived reference, which
+/// `self_referencing` enforces so callers never juggle the raw lifetime themselves.
+#[self_referencing]
+pub struct ArchivedModuleHandle {
+    mmap: Mmap,
+    #[borrows(mmap)]
+    #[covariant]
+    archived: &'this ArchivedCachedModule,
+}
+
+impl ArchivedModuleHandle {
+    /// Borrows the archived module this handle owns. Fields can be read directly off it
+    /// (`handle.get().name.as_str()`, `handle.get().files[i].path.as_str()`, ...) without
+    /// deserializing the rest of the tree.
+    pub fn get(&self) -> &ArchivedCachedModule {
+        *self.borrow_archived()
+    }
+}
+
+/// Serializes `module` with rkyv and writes it to `path`, preceded by a small fixed header
+/// (magic + format version) so [`load_module_rkyv`] can validate the file before trusting it.
+pub fn save_module_rkyv(path: &Path, module: &CachedModule) -> bool {
+    let bytes = match rkyv::to_bytes::<_, 4096>(module) {
+        Ok(b) => b,
+        Err(e) => {
+            warn!("Failed to archive module cache with rkyv: {}", e);
+            return false;
+        }
+    };
+
+    let mut file = match File::create(path) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!("Failed to create rkyv module cache file {:?}: {}", path, e);
+            return false;
+        }
+    };
+
+    if let Err(e) = file.write_all(&RKYV_CACHE_MAGIC.to_le_bytes())
+        .and_then(|_| file.write_all(&RKYV_CACHE_VERSION.to_le_bytes()))
+        .and_then(|_| file.write_all(&bytes))
+    {
+        warn!("Failed to write rkyv module cache file {:?}: {}", path, e);
+        return false;
+    }
+
+    true
+}
+
+/// Memory-maps `path` and validates its header, returning an [`ArchivedModuleHandle`] that
+/// exposes `&ArchivedCachedModule` without copying the mapped bytes. Any header mismatch or I/O
+/// error is treated as a cache miss, same as the bincode path.
+pub fn load_module_rkyv(path: &Path) -> Option<ArchivedModuleHandle> {
+    if !path.exists() {
+        return None;
+    }
+
+    let file = match File::open(path) {
+        Ok(f) => f,
+        Err(e) => {
+            warn!("Failed to open rkyv module cache file {:?}: {}", path, e);
+            return None;
+        }
+    };
+
+    let mmap = match unsafe { Mmap::map(&file) } {
+        Ok(m) => m,
+        Err(e) => {
+            warn!("Failed to mmap rkyv module cache file {:?}: {}", path, e);
+            return None;
+        }
+    };
+
+    if mmap.len() < HEADER_LEN {
+        warn!("rkyv module cache file {:?} is too small to contain a header", path);
+        return None;
+    }
+
+    let magic = u32::from_le_bytes(mmap[0..4].try_into().unwrap());
+    if magic != RKYV_CACHE_MAGIC {
+        warn!("rkyv module cache file {:?} has an invalid magic number", path);
+        return None;
+    }
+    let version = u32::from_le_bytes(mmap[4..8].try_into().unwrap());
+    if version != RKYV_CACHE_VERSION {
+        warn!("rkyv module cache file {:?} version mismatch (got {}, expected {})", path, version, RKYV_CACHE_VERSION);
+        return None;
+    }
+
+    let handle = ArchivedModuleHandleTryBuilder {
+        mmap,
+        archived_builder: |mmap: &Mmap| {
+            rkyv::check_archived_root::<CachedModule>(&mmap[HEADER_LEN..])
+                .map_err(|e| format!("rkyv validation failed: {}", e))
+        },
+    }.try_build();
+
+    match handle {
+        Ok(h) => Some(h),
+        Err(e) => {
+            warn!("Failed to validate archived module cache {:?}: {}", path, e);
+            None
+        }
+    }
+}
+
+pub fn remove_rkyv_cache(path: &Path) {
+    if path.exists() {
+        if let Err(e) = fs::remove_file(path) {
+            warn!("Failed to remove rkyv module cache file {:?}: {}", path, e);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+//! Opt-in timing of the major resolution phases (`SyncOdoo` build/arch/validation, `follow_ref`
+//! type resolution, ...), modeled on rustc's `SelfProfiler`: a lightweight counter bumped by a
+//! scope guard around each phase, so a slow workspace can be diagnosed without attaching a real
+//! profiler. Gated behind [`crate::core::config::ConfigEntry::self_profile_enabled`] - when that's
+//! off, [`SelfProfiler::enter`] is a no-op guard that doesn't even read the clock.
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy, Default)]
+struct PhaseStats {
+    total: Duration,
+    count: u64,
+}
+
+/// Accumulates per-phase timing for one `SyncOdoo` instance. One lives on `SyncOdoo` alongside
+/// its other build-state counters, so every session sharing that `SyncOdoo` contributes to the
+/// same summary.
+#[derive(Debug, Default)]
+pub struct SelfProfiler {
+    enabled: bool,
+    phases: HashMap<&'static str, PhaseStats>,
+}
+
+impl SelfProfiler {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled, phases: HashMap::new() }
+    }
+
+    pub fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+
+    /// Starts timing `phase`. The returned guard records its elapsed time into `self` when
+    /// dropped - `#[must_use]` so `profiler.enter("foo");` (timing nothing, since the guard would
+    /// be dropped immediately) doesn't silently compile.
+    #[must_use]
+    pub fn enter(&mut self, phase: &'static str) -> ProfilerGuard<'_> {
+        ProfilerGuard {
+            profiler: self,
+            phase,
+            start: if self.enabled { Some(Instant::now()) } else { None },
+        }
+    }
+
+    fn record(&mut self, phase: &'static str, elapsed: Duration) {
+        let stats = self.phases.entry(phase).or_default();
+        stats.total += elapsed;
+        stats.count += 1;
+    }
+
+    /// A human-readable summary, one line per phase, sorted by total time descending (the phases
+    /// most worth investigating first) - suitable for logging on session end or a `$Odoo/selfProfile`
+    /// on-demand request.
+    pub fn summary(&self) -> String {
+        if !self.enabled {
+            return "self-profiling is disabled".to_string();
+        }
+        let mut rows: Vec<(&'static str, PhaseStats)> = self.phases.iter().map(|(k, v)| (*k, *v)).collect();
+        rows.sort_by(|a, b| b.1.total.cmp(&a.1.total));
+
+        let mut out = String::new();
+        for (phase, stats) in rows {
+            out.push_str(&format!(
+                "{phase:<24} {:>10.3}ms total  {:>8} calls  {:>8.3}ms avg\n",
+                stats.total.as_secs_f64() * 1000.0,
+                stats.count,
+                (stats.total.as_secs_f64() * 1000.0) / stats.count.max(1) as f64,
+            ));
+        }
+        out
+    }
+
+    /// The same data as [`Self::summary`], as a JSON event stream suitable for a `--profile-json`
+    /// flag or a trace viewer, one object per phase.
+    pub fn to_json(&self) -> serde_json::Value {
+        let events: Vec<serde_json::Value> = self.phases.iter().map(|(phase, stats)| {
+            serde_json::json!({
+                "phase": phase,
+                "total_ms": stats.total.as_secs_f64() * 1000.0,
+                "count": stats.count,
+            })
+        }).collect();
+        serde_json::json!({ "phases": events })
+    }
+}
+
+/// RAII scope timer returned by [`SelfProfiler::enter`]. Recording only happens in `Drop`, so
+/// early returns inside the timed scope (the common case - a phase bailing out on an error) still
+/// get measured correctly.
+pub struct ProfilerGuard<'a> {
+    profiler: &'a mut SelfProfiler,
+    phase: &'static str,
+    start: Option<Instant>,
+}
+
+impl Drop for ProfilerGuard<'_> {
+    fn drop(&mut self) {
+        if let Some(start) = self.start {
+            let elapsed = start.elapsed();
+            self.profiler.record(self.phase, elapsed);
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::Mutex;
+use std::sync::OnceLock;
+
+use fst::automaton::{Levenshtein, Subsequence};
+use fst::{Automaton, IntoStreamer, Map as FstMap, MapBuilder, Streamer};
+use lsp_types::SymbolKind;
+use ruff_text_size::TextRange;
+
+/// Everything needed to materialize a `WorkspaceSymbol` for one indexed name, without keeping a
+/// live reference into the `Symbol` tree - the index outlives any single request/borrow.
+#[derive(Debug, Clone)]
+pub struct SymbolLocator {
+    pub name: String,
+    pub kind: SymbolKind,
+    pub container_name: Option<String>,
+    pub path: String,
+    pub range: Option<TextRange>,
+}
+
+/// FST-backed index of every indexable name contributed by one Odoo module: symbol names, quoted
+/// model names (`"res.partner"`), and `xmlid.`-prefixed external ids. Keys are lowercased for
+/// case-insensitive search; `groups` holds, for each distinct lowercased key, every
+/// [`SymbolLocator`] that produced it (two fields on different classes can share a name).
+pub struct ModuleSymbolIndex {
+    map: FstMap<Vec<u8>>,
+    groups: Vec<Vec<SymbolLocator>>,
+}
+
+impl ModuleSymbolIndex {
+    /// Builds an index from `entries` (key, locator) pairs collected by walking a module's
+    /// symbol subtree once. `entries` doesn't need to be pre-sorted or pre-deduplicated.
+    pub fn build(entries: Vec<(String, SymbolLocator)>) -> Self {
+        let mut grouped: BTreeMap<String, Vec<SymbolLocator>> = BTreeMap::new();
+        for (key, locator) in entries {
+            grouped.entry(key.to_lowercase()).or_default().push(locator);
+        }
+
+        let mut builder = MapBuilder::memory();
+        let mut groups = Vec::with_capacity(grouped.len());
+        for (key, locators) in grouped.into_iter() {
+            // `BTreeMap`'s iteration order is sorted, which is exactly what `MapBuilder::insert`
+            // requires (strictly increasing keys).
+            let _ = builder.insert(key.as_bytes(), groups.len() as u64);
+            groups.push(locators);
+        }
+        let map = builder.into_map();
+        Self { map, groups }
+    }
+
+    /// Returns every locator whose lowercased name is a fuzzy match for `query`: either a
+    /// subsequence of it (same rule as `string_fuzzy_contains`) or within a small Levenshtein
+    /// distance scaled to the query's length, for typo tolerance. Enumerated in sorted-key order.
+    pub fn search(&self, query: &str) -> Vec<&SymbolLocator> {
+        if query.is_empty() {
+            return self.groups.iter().flatten().collect();
+        }
+        let query = query.to_lowercase();
+        let max_edits = if query.len() <= 3 { 0 } else if query.len() <= 6 { 1 } else { 2 };
+
+        let mut matched_groups = Vec::new();
+        match Levenshtein::new(&query, max_edits) {
+            Ok(levenshtein) => {
+                let automaton = Subsequence::new(&query).union(levenshtein);
+                let mut stream = self.map.search(automaton).into_stream();
+                while let Some((_, idx)) = stream.next() {
+                    matched_groups.push(idx);
+                }
+            }
+            Err(_) => {
+                // Query too long/unusual for a bounded Levenshtein automaton - fall back to the
+                // plain subsequence match rather than failing the whole lookup.
+                let mut stream = self.map.search(Subsequence::new(&query)).into_stream();
+                while let Some((_, idx)) = stream.next() {
+                    matched_groups.push(idx);
+                }
+            }
+        }
+
+        matched_groups.into_iter().flat_map(|idx| self.groups[idx as usize].iter()).collect()
+    }
+}
+
+/// Caches one [`ModuleSymbolIndex`] per Odoo module, keyed by the module root symbol's sanitized
+/// path, so a single changed file only needs its owning module's segment rebuilt instead of
+/// re-walking the whole workspace tree on every `workspace/symbol` request.
+fn module_index_cache() -> &'static Mutex<HashMap<String, ModuleSymbolIndex>> {
+    static CACHE: OnceLock<Mutex<HashMap<String, ModuleSymbolIndex>>> = OnceLock::new();
+    CACHE.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Returns whether `module_key` already has a cached index (so callers only pay to walk the
+/// symbol tree and rebuild it when it's missing or has been evicted).
+pub fn has_module_index(module_key: &str) -> bool {
+    module_index_cache().lock().unwrap().contains_key(module_key)
+}
+
+/// Rebuilds (or inserts) the cached index for a single module from freshly-collected `entries`.
+/// Other modules' cached indices are left untouched.
+pub fn rebuild_module_index(module_key: &str, entries: Vec<(String, SymbolLocator)>) {
+    let index = ModuleSymbolIndex::build(entries);
+    module_index_cache().lock().unwrap().insert(module_key.to_string(), index);
+}
+
+/// Evicts a module's cached index, e.g. when a file inside it changes or the module is unloaded,
+/// so the next query rebuilds just that module's segment.
+pub fn evict_module_index(module_key: &str) {
+    module_index_cache().lock().unwrap().remove(module_key);
+}
+
+/// Evicts every cached module index whose key is an ancestor of `path` (a file inside that
+/// module changed) or is itself inside `path` (a whole module directory was removed/renamed).
+/// Called from `FileMgr::delete_path`/`rename_path` so a single changed file only invalidates
+/// the module(s) it actually touches instead of the whole index.
+pub fn evict_modules_touching(path: &str) {
+    module_index_cache().lock().unwrap().retain(|module_key, _| {
+        !(path.starts_with(module_key.as_str()) || module_key.starts_with(path))
+    });
+}
+
+/// Searches every currently-cached module index for `query`, returning the union of matches.
+/// Modules that haven't been (re)indexed yet simply contribute nothing until the caller rebuilds
+/// them via [`rebuild_module_index`].
+pub fn search_all_modules(query: &str) -> Vec<SymbolLocator> {
+    module_index_cache()
+        .lock()
+        .unwrap()
+        .values()
+        .flat_map(|index| index.search(query).into_iter().cloned())
+        .collect()
+}
@@ -0,0 +1,165 @@
+use std::sync::{Mutex, OnceLock};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tracing::warn;
+
+/// One row of a file's contribution to the workspace symbol index, flattened to plain columns so
+/// it round-trips through SQLite without needing `Symbol` itself to be `Serialize`.
+#[derive(Debug, Clone)]
+pub struct CachedSymbolRow {
+    pub name: String,
+    pub kind: i32,
+    pub container: Option<String>,
+    pub path: String,
+    pub range_start: u32,
+    pub range_end: u32,
+}
+
+/// A cache entry identified by its own key (typically a file's sanitized path) whose stored rows
+/// are only trusted while a content hash/mtime still matches what they were generated from.
+pub trait Cached {
+    /// Name of the SQLite table this type's rows live in - implementations that cache different
+    /// kinds of rows get their own table instead of sharing one.
+    fn sql_table() -> &'static str;
+    /// The cache key identifying this entry, e.g. the file's sanitized path.
+    fn key(&self) -> String;
+
+    /// Returns the rows stored for `self.key()` if their stored hash still equals
+    /// `content_hash`; otherwise calls `generate`, persists its result keyed by `content_hash`,
+    /// and returns that instead. Any SQLite error is treated the same as a cache miss - logged
+    /// and fallen back to regenerating, never propagated as fatal.
+    fn cached<F>(&self, con: &Connection, content_hash: u64, generate: F) -> Vec<CachedSymbolRow>
+    where
+        F: FnOnce() -> Vec<CachedSymbolRow>,
+    {
+        let key = self.key();
+        match load_rows(con, Self::sql_table(), &key, content_hash) {
+            Ok(Some(rows)) => return rows,
+            Ok(None) => {}
+            Err(e) => warn!("Workspace symbol cache lookup failed for {}: {}", key, e),
+        }
+
+        let rows = generate();
+        if let Err(e) = store_rows(con, Self::sql_table(), &key, content_hash, &rows) {
+            warn!("Failed to persist workspace symbol cache for {}: {}", key, e);
+        }
+        rows
+    }
+}
+
+/// Creates `table` if it doesn't already exist, with the fixed column set every [`Cached`]
+/// implementation shares. Safe to call on every connection open - `CREATE TABLE IF NOT EXISTS`.
+pub fn ensure_schema(con: &Connection, table: &str) -> rusqlite::Result<()> {
+    con.execute(
+        &format!(
+            "CREATE TABLE IF NOT EXISTS {table} (
+                path TEXT NOT NULL,
+                content_hash INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                kind INTEGER NOT NULL,
+                container TEXT,
+                range_start INTEGER NOT NULL,
+                range_end INTEGER NOT NULL
+            )"
+        ),
+        [],
+    )?;
+    con.execute(
+        &format!("CREATE INDEX IF NOT EXISTS idx_{table}_path ON {table}(path)"),
+        [],
+    )?;
+    Ok(())
+}
+
+fn load_rows(con: &Connection, table: &str, key: &str, content_hash: u64) -> rusqlite::Result<Option<Vec<CachedSymbolRow>>> {
+    let stored_hash: Option<i64> = con
+        .query_row(&format!("SELECT content_hash FROM {table} WHERE path = ?1 LIMIT 1"), params![key], |row| row.get(0))
+        .optional()?;
+    let Some(stored_hash) = stored_hash else {
+        return Ok(None);
+    };
+    if stored_hash as u64 != content_hash {
+        return Ok(None);
+    }
+
+    let mut stmt = con.prepare(&format!("SELECT name, kind, container, path, range_start, range_end FROM {table} WHERE path = ?1"))?;
+    let rows = stmt.query_map(params![key], |row| {
+        Ok(CachedSymbolRow {
+            name: row.get(0)?,
+            kind: row.get(1)?,
+            container: row.get(2)?,
+            path: row.get(3)?,
+            range_start: row.get(4)?,
+            range_end: row.get(5)?,
+        })
+    })?.collect::<rusqlite::Result<Vec<_>>>()?;
+    Ok(Some(rows))
+}
+
+fn store_rows(con: &Connection, table: &str, key: &str, content_hash: u64, rows: &[CachedSymbolRow]) -> rusqlite::Result<()> {
+    con.execute(&format!("DELETE FROM {table} WHERE path = ?1"), params![key])?;
+    for row in rows {
+        con.execute(
+            &format!("INSERT INTO {table} (path, content_hash, name, kind, container, range_start, range_end) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"),
+            params![key, content_hash as i64, row.name, row.kind, row.container, row.range_start, row.range_end],
+        )?;
+    }
+    Ok(())
+}
+
+/// Opens (creating if needed) the workspace-wide SQLite cache database used by every [`Cached`]
+/// implementation in this module, under the same `dirs::data_local_dir()/odoo-ls` directory as
+/// the other on-disk caches.
+pub fn open_cache_db() -> Option<Connection> {
+    let cache_dir = dirs::data_local_dir()?.join("odoo-ls");
+    if !cache_dir.exists() {
+        if let Err(e) = std::fs::create_dir_all(&cache_dir) {
+            warn!("Failed to create workspace symbol cache directory: {}", e);
+            return None;
+        }
+    }
+    match Connection::open(cache_dir.join("workspace_symbols.sqlite")) {
+        Ok(con) => Some(con),
+        Err(e) => {
+            warn!("Failed to open workspace symbol cache database: {}", e);
+            None
+        }
+    }
+}
+
+/// Cache key for one file's contribution to the workspace symbol index - the [`Cached`] impl
+/// callers actually use from `WorkspaceSymbolFeature`.
+pub struct FileSymbolCacheKey {
+    pub path: String,
+}
+
+impl Cached for FileSymbolCacheKey {
+    fn sql_table() -> &'static str {
+        "workspace_symbol_cache"
+    }
+
+    fn key(&self) -> String {
+        self.path.clone()
+    }
+}
+
+fn db_connection() -> &'static Mutex<Option<Connection>> {
+    static CONN: OnceLock<Mutex<Option<Connection>>> = OnceLock::new();
+    CONN.get_or_init(|| {
+        let con = open_cache_db();
+        if let Some(con) = con.as_ref() {
+            if let Err(e) = ensure_schema(con, FileSymbolCacheKey::sql_table()) {
+                warn!("Failed to initialize workspace symbol cache schema: {}", e);
+            }
+        }
+        Mutex::new(con)
+    })
+}
+
+/// Runs `f` against the shared workspace symbol cache connection, if it could be opened. Used by
+/// `WorkspaceSymbolFeature` so the connection (and its schema setup) is only established once per
+/// server process instead of once per file.
+pub fn with_cache<R>(f: impl FnOnce(&Connection) -> R) -> Option<R> {
+    let guard = db_connection().lock().unwrap();
+    guard.as_ref().map(f)
+}
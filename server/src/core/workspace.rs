@@ -0,0 +1,109 @@
+use std::path::PathBuf;
+
+use lsp_types::{DidChangeWorkspaceFoldersParams, WorkspaceFolder};
+
+use crate::core::config::ConfigEntry;
+use crate::core::entry_point::EntryPointMgr;
+use crate::core::file_mgr::FileMgr;
+use crate::threads::SessionInfo;
+
+/// A single workspace root: its own addons search path, resolved independently of any other
+/// root open in the same editor window. Real Odoo setups layer several such roots (enterprise,
+/// community, a custom project) and a file's diagnostics must be computed against the module
+/// graph of the root it actually belongs to, not whichever root happened to load first.
+///
+/// `config` is `None` until the root's own `ConfigEntry` is resolved (e.g. from a
+/// `.odoo_ls.cfg`-style file at the root, or from client-sent settings scoped to that folder);
+/// a root with no declared config falls back to the nearest ancestor root that has one, mirroring
+/// how editors resolve per-folder settings by walking up to the owning root.
+#[derive(Debug, Clone)]
+pub struct WorkspaceRoot {
+    pub uri: String,
+    pub path: String,
+    pub addons_paths: Vec<PathBuf>,
+    pub config: Option<ConfigEntry>,
+}
+
+/// Tracks the set of workspace roots currently open, and routes a file path to the root whose
+/// addons graph it belongs to. Reacts to `workspace/didChangeWorkspaceFolders` incrementally so
+/// adding or removing a root never requires a full server restart.
+#[derive(Debug, Default)]
+pub struct WorkspaceManager {
+    roots: Vec<WorkspaceRoot>,
+}
+
+impl WorkspaceManager {
+    pub fn new() -> Self {
+        Self { roots: Vec::new() }
+    }
+
+    /// Replaces the whole set of roots, e.g. from the `initialize` request's `workspaceFolders`.
+    pub fn set_workspace_folders(&mut self, folders: &[WorkspaceFolder]) {
+        self.roots = folders.iter().map(|f| WorkspaceManager::root_from_folder(f)).collect();
+    }
+
+    pub fn add_root(&mut self, folder: &WorkspaceFolder) {
+        if !self.roots.iter().any(|r| r.uri == folder.uri.as_str()) {
+            self.roots.push(WorkspaceManager::root_from_folder(folder));
+        }
+    }
+
+    pub fn remove_root(&mut self, uri: &str) {
+        self.roots.retain(|r| r.uri != uri);
+    }
+
+    /// Applies an incremental `workspace/didChangeWorkspaceFolders` notification.
+    pub fn handle_did_change_workspace_folders(&mut self, params: &DidChangeWorkspaceFoldersParams) {
+        for added in &params.event.added {
+            self.add_root(added);
+        }
+        for removed in &params.event.removed {
+            self.remove_root(removed.uri.as_str());
+        }
+    }
+
+    /// Finds the root that owns `path`, picking the most specific (longest) matching root path
+    /// when roots are nested.
+    pub fn resolve_root_for_path(&self, path: &str) -> Option<&WorkspaceRoot> {
+        self.roots.iter()
+            .filter(|r| path.starts_with(&r.path))
+            .max_by_key(|r| r.path.len())
+    }
+
+    /// Resolves the `ConfigEntry` that should govern `path`: the owning root's own config if it
+    /// declares one, otherwise the nearest ancestor root (by path prefix) that does. Returns
+    /// `None` only when no root on the path to `path` has a config at all.
+    pub fn resolve_config_for_path(&self, path: &str) -> Option<&ConfigEntry> {
+        let mut candidates: Vec<&WorkspaceRoot> = self.roots.iter()
+            .filter(|r| path.starts_with(&r.path))
+            .collect();
+        candidates.sort_by_key(|r| std::cmp::Reverse(r.path.len()));
+        candidates.into_iter().find_map(|r| r.config.as_ref())
+    }
+
+    pub fn set_root_config(&mut self, uri: &str, config: ConfigEntry) {
+        if let Some(root) = self.roots.iter_mut().find(|r| r.uri == uri) {
+            root.config = Some(config);
+        }
+    }
+
+    pub fn roots(&self) -> &[WorkspaceRoot] {
+        &self.roots
+    }
+
+    /// Creates one dedicated `EntryPointMgr` entry point per configured root, so two addons
+    /// trees with different Odoo versions (and different `odoo_path`/`python_path`) can each be
+    /// analyzed against their own config within a single server session.
+    pub fn create_entry_points(&self, session: &mut SessionInfo) {
+        for root in self.roots.iter() {
+            if root.config.is_some() {
+                EntryPointMgr::create_new_custom_entry_for_path(session, &PathBuf::from(&root.path), &PathBuf::from(&root.path));
+            }
+        }
+    }
+
+    fn root_from_folder(folder: &WorkspaceFolder) -> WorkspaceRoot {
+        let path = FileMgr::uri2pathname(folder.uri.as_str());
+        WorkspaceRoot { uri: folder.uri.as_str().to_string(), path, addons_paths: Vec::new(), config: None }
+    }
+}
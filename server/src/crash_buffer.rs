@@ -1,29 +1,104 @@
 
 use lsp_server::Message;
+use serde::{Deserialize, Serialize};
+use std::backtrace::Backtrace;
 use std::collections::VecDeque;
+use std::fs;
+use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 use std::sync::OnceLock;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tracing::{error, warn};
 
-const N: usize = 20;
+const DEFAULT_N: usize = 20;
 
-pub static CRASH_BUFFER: OnceLock<Mutex<VecDeque<Message>>> = OnceLock::new();
+struct CrashBuffer {
+    capacity: usize,
+    messages: VecDeque<Message>,
+}
+
+pub static CRASH_BUFFER: OnceLock<Mutex<CrashBuffer>> = OnceLock::new();
 
-pub fn init_crash_buffer() {
-    let _ = CRASH_BUFFER.set(Mutex::new(VecDeque::with_capacity(N)));
+/// Directory crash reports are written to when a panic hook is installed via
+/// [`install_crash_reporter`]. Defaults to the current directory if never set.
+pub static CRASH_DUMP_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// A replayable snapshot of the messages that led to a panic, along with enough context for a
+/// maintainer to understand what happened without a live repro.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CrashReport {
+    pub timestamp_secs: u64,
+    pub thread_name: String,
+    pub panic_message: String,
+    pub backtrace: String,
+    pub messages: Vec<Message>,
+}
+
+pub fn init_crash_buffer(capacity: usize) {
+    let _ = CRASH_BUFFER.set(Mutex::new(CrashBuffer { capacity, messages: VecDeque::with_capacity(capacity) }));
 }
 
 pub fn push_message(msg: Message) {
     if let Some(buffer) = CRASH_BUFFER.get() {
         let mut buf = buffer.lock().unwrap();
-        if buf.len() == N { buf.pop_front(); }
-        buf.push_back(msg);
+        if buf.messages.len() == buf.capacity { buf.messages.pop_front(); }
+        buf.messages.push_back(msg);
     }
 }
 
 pub fn get_messages() -> Vec<Message> {
     if let Some(buffer) = CRASH_BUFFER.get() {
-        buffer.lock().unwrap().iter().cloned().collect()
+        buffer.lock().unwrap().messages.iter().cloned().collect()
     } else {
         Vec::new()
     }
 }
+
+/// Installs a panic hook that, in addition to chaining the previous hook, flushes the
+/// crash-message ring buffer plus the panic payload and a backtrace to a timestamped JSON file
+/// under `dump_dir`. Call once during startup, after [`init_crash_buffer`].
+pub fn install_crash_reporter(dump_dir: PathBuf) {
+    let _ = CRASH_DUMP_DIR.set(dump_dir);
+    let previous_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |panic_info| {
+        previous_hook(panic_info);
+        if let Err(e) = write_crash_report(panic_info) {
+            error!("Failed to write crash report: {}", e);
+        }
+    }));
+}
+
+fn write_crash_report(panic_info: &std::panic::PanicHookInfo) -> std::io::Result<()> {
+    let dump_dir = CRASH_DUMP_DIR.get().cloned().unwrap_or_else(|| PathBuf::from("."));
+    fs::create_dir_all(&dump_dir)?;
+
+    let timestamp_secs = SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let thread_name = std::thread::current().name().unwrap_or("unknown").to_string();
+    let panic_message = panic_info.payload().downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| panic_info.payload().downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic payload".to_string());
+    let backtrace = Backtrace::force_capture().to_string();
+
+    let report = CrashReport {
+        timestamp_secs,
+        thread_name,
+        panic_message,
+        backtrace,
+        messages: get_messages(),
+    };
+
+    let report_path = dump_dir.join(format!("odoo-ls-crash-{}.json", timestamp_secs));
+    let file = fs::File::create(&report_path)?;
+    serde_json::to_writer_pretty(file, &report)?;
+    warn!("Crash report written to {}", report_path.display());
+    Ok(())
+}
+
+/// Reads back a crash report written by [`install_crash_reporter`] and returns its captured
+/// message sequence, so a maintainer can feed it through the dispatch loop to reproduce the
+/// crash.
+pub fn replay_crash(path: &Path) -> std::io::Result<Vec<Message>> {
+    let file = fs::File::open(path)?;
+    let report: CrashReport = serde_json::from_reader(file)?;
+    Ok(report.messages)
+}
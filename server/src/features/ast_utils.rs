@@ -7,8 +7,10 @@ use crate::core::odoo::SyncOdoo;
 use crate::core::import_resolver::{resolve_from_stmt, resolve_import_stmt};
 use crate::core::symbols::symbol::Symbol;
 use crate::core::file_mgr::{FileInfo, FileInfoAst};
+use crate::features::code_actions::QuickFixDescriptor;
 use crate::threads::SessionInfo;
 use crate::S;
+use lsp_types::{CompletionItem, CompletionItemKind, CompletionList, CompletionResponse, Diagnostic, DiagnosticSeverity};
 use ruff_python_ast::name::Name;
 use ruff_python_ast::visitor::{Visitor, walk_expr, walk_stmt, walk_alias, walk_except_handler, walk_parameter, walk_keyword, walk_pattern_keyword, walk_type_param, walk_pattern};
 use ruff_python_ast::{Alias, AtomicNodeIndex, ExceptHandler, Expr, ExprCall, Identifier, Keyword, Parameter, Pattern, PatternKeyword, Stmt, TypeParam};
@@ -111,8 +113,9 @@ impl AstUtils {
                         };
                         if !is_last {
                             //we import as a from_stmt, to refuse import of variables, as the import stmt is not complete
+                            let segment = to_analyze.to_string();
                             let to_analyze = Identifier { id: Name::new(to_analyze), range: TextRange::new(TextSize::new(0), TextSize::new(0)), node_index: AtomicNodeIndex::default() };
-                            let (from_symbol, _fallback_sym, _file_tree) = resolve_from_stmt(session, file_symbol, Some(&to_analyze), 0);
+                            let (from_symbol, fallback_sym, _file_tree) = resolve_from_stmt(session, file_symbol, Some(&to_analyze), 0);
                             if let Some(symbol) = from_symbol {
                                 let result = AnalyzeAstResult {
                                     evaluations: vec![Evaluation::eval_from_symbol(&Rc::downgrade(&symbol), None)],
@@ -120,6 +123,10 @@ impl AstUtils {
                                 };
                                 return Some((result, Some(range)));
                             }
+                            let diagnostics = Self::suggest_for_unresolved_segment(session, file_symbol, fallback_sym.as_ref(), &segment, range);
+                            if !diagnostics.is_empty() {
+                                return Some((AnalyzeAstResult { evaluations: vec![], diagnostics }, Some(range)));
+                            }
                         } else {
                             let res = resolve_import_stmt(session, file_symbol, None, &[
                                 Alias { //create a dummy alias with a asname to force full import
@@ -160,8 +167,9 @@ impl AstUtils {
                     } else {
                         return None;
                     };
+                    let segment = to_analyze.to_string();
                     let to_analyze = Identifier { id: Name::new(to_analyze), range: TextRange::new(TextSize::new(0), TextSize::new(0)), node_index: AtomicNodeIndex::default() };
-                    let (from_symbol, _fallback_sym, _file_tree) = resolve_from_stmt(session, file_symbol, Some(&to_analyze), 0);
+                    let (from_symbol, fallback_sym, _file_tree) = resolve_from_stmt(session, file_symbol, Some(&to_analyze), 0);
                     if let Some(symbol) = from_symbol {
                         let result = AnalyzeAstResult {
                             evaluations: vec![Evaluation::eval_from_symbol(&Rc::downgrade(&symbol), None)],
@@ -169,6 +177,10 @@ impl AstUtils {
                         };
                         return Some((result, Some(range)));
                     }
+                    let diagnostics = Self::suggest_for_unresolved_segment(session, file_symbol, fallback_sym.as_ref(), &segment, range);
+                    if !diagnostics.is_empty() {
+                        return Some((AnalyzeAstResult { evaluations: vec![], diagnostics }, Some(range)));
+                    }
                 }
             },
             _ => {
@@ -177,6 +189,171 @@ impl AstUtils {
         }
         None
     }
+
+    /// Powers autocompletion for `import a.b.<cursor>` / `from a.b.<cursor> import`: resolves the
+    /// prefix up to the last dot before `offset` (reusing the same slicing `get_symbol_in_import`
+    /// uses to locate the segment under the cursor), then lists the child package/module symbols
+    /// of the resolved parent as completion candidates.
+    pub fn complete_in_import(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, offset: u32, stmt: &Stmt) -> Option<CompletionResponse> {
+        let (full_text, name_start) = match stmt {
+            Stmt::Import(stmt) => {
+                let alias = stmt.names.iter().find(|a| a.name.range().contains(TextSize::new(offset)))?;
+                (alias.name.id.as_str(), alias.name.range().start().to_usize())
+            }
+            Stmt::ImportFrom(stmt) => {
+                let module = stmt.module.as_ref()?;
+                if !module.range().contains(TextSize::new(offset)) {
+                    return None;
+                }
+                (module.id.as_str(), module.range().start().to_usize())
+            }
+            _ => return None,
+        };
+        let rel_offset = (offset as usize).saturating_sub(name_start).min(full_text.len());
+        let typed = &full_text[..rel_offset];
+        let last_dot = typed.rfind('.')?;
+        let prefix = &typed[..last_dot];
+
+        let to_analyze = Identifier { id: Name::new(prefix), range: TextRange::new(TextSize::new(0), TextSize::new(0)), node_index: AtomicNodeIndex::default() };
+        let (from_symbol, _fallback_sym, _file_tree) = resolve_from_stmt(session, file_symbol, Some(&to_analyze), 0);
+        let parent = from_symbol?;
+
+        let items: Vec<CompletionItem> = parent.borrow().all_symbols().filter_map(|child| {
+            let child_ref = child.borrow();
+            let kind = match child_ref.typ() {
+                SymType::PACKAGE(_) => CompletionItemKind::MODULE,
+                SymType::FILE => CompletionItemKind::FILE,
+                _ => return None,
+            };
+            Some(CompletionItem {
+                label: child_ref.name().to_string(),
+                kind: Some(kind),
+                ..Default::default()
+            })
+        }).collect();
+
+        if items.is_empty() {
+            None
+        } else {
+            Some(CompletionResponse::List(CompletionList { is_incomplete: false, items }))
+        }
+    }
+
+    /// Builds a "did you mean" hint diagnostic when an import segment fails to resolve, mirroring
+    /// rustc's `find_best_match_for_name`: the candidate set is the child symbols of the last
+    /// successfully-resolved package (`parent`), and we keep only the closest one within a
+    /// reasonable edit distance of `failed_name`.
+    fn suggest_for_unresolved_segment(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, parent: Option<&Rc<RefCell<Symbol>>>, failed_name: &str, range: TextRange) -> Vec<Diagnostic> {
+        if failed_name.len() < 3 {
+            return vec![];
+        }
+        let Some(parent) = parent else { return vec![] };
+        let candidates: Vec<String> = parent.borrow().all_symbols().map(|s| s.borrow().name().to_string()).collect();
+        let Some(suggestion) = find_best_match_for_name(failed_name, candidates.iter().map(|s| s.as_str())) else {
+            return vec![];
+        };
+        let Some(path) = file_symbol.borrow().paths().first().cloned() else { return vec![] };
+        let lsp_range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &range);
+        let diagnostic = Diagnostic {
+            range: lsp_range,
+            severity: Some(DiagnosticSeverity::HINT),
+            message: format!("unknown submodule `{}`, did you mean `{}`?", failed_name, suggestion),
+            ..Default::default()
+        };
+        vec![QuickFixDescriptor::RenameUnresolvedReference { range: lsp_range, suggestion: suggestion.to_string() }.attach_to(diagnostic)]
+    }
+}
+
+/// Computes the Damerau-Levenshtein distance between `a` and `b` (insertion, deletion and
+/// substitution cost 1, adjacent transposition cost 1).
+fn damerau_levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (len_a, len_b) = (a.len(), b.len());
+    let mut d = vec![vec![0usize; len_b + 1]; len_a + 1];
+    for i in 0..=len_a {
+        d[i][0] = i;
+    }
+    for j in 0..=len_b {
+        d[0][j] = j;
+    }
+    for i in 1..=len_a {
+        for j in 1..=len_b {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+    d[len_a][len_b]
+}
+
+/// Finds the closest candidate to `name` by Damerau-Levenshtein distance, modeled on rustc's
+/// `find_best_match_for_name`: only candidates within `max(name.len(), candidate.len()) / 3`
+/// (at least 1) are considered, a pure case difference counts as distance 0, and ties are broken
+/// by lowest distance first, then by the order `candidates` was given in (so the first-declared
+/// symbol wins a tie).
+pub(crate) fn find_best_match_for_name<'a>(name: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    candidates
+        .filter_map(|candidate| {
+            let distance = if name.eq_ignore_ascii_case(candidate) {
+                0
+            } else {
+                damerau_levenshtein_distance(name, candidate)
+            };
+            let threshold = (name.len().max(candidate.len()) / 3).max(1);
+            if distance <= threshold { Some((distance, candidate)) } else { None }
+        })
+        .min_by_key(|(dist, _)| *dist)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Same matching rule as [`find_best_match_for_name`] (same threshold, same distance-0 case-fold
+/// rule), but for candidate sets that don't have a meaningful "declaration order" to break ties
+/// with - model names out of `session.sync_odoo.models`, or a class's method/field names scanned
+/// for a `compute=`/`related=`/`inverse=`/`search=` string argument. Ties are instead broken in
+/// favor of a candidate that appears as a case-insensitive substring of `typed` (or vice versa) -
+/// e.g. `"user"` typo'd against `"uses"`/`"users"` (both at distance 1) prefers `"users"` since
+/// `"user"` is a substring of it, whereas pure edit distance alone could go either way.
+///
+/// Intended for the `_inherit`/`_name`/comodel-string and compute/related/inverse/search-argument
+/// resolution paths once they grow a diagnostics/completion pass of their own; `typed` is rejected
+/// outright (returns `None`) if empty, and `candidates` is capped at `MAX_CANDIDATES` entries so a
+/// very large model registry can't turn every unresolved reference into an O(n) distance scan.
+pub(crate) fn find_best_model_or_member_match<'a>(typed: &str, candidates: impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    const MAX_CANDIDATES: usize = 4096;
+    if typed.is_empty() {
+        return None;
+    }
+    candidates
+        .take(MAX_CANDIDATES)
+        .filter_map(|candidate| {
+            let distance = if typed.eq_ignore_ascii_case(candidate) {
+                0
+            } else {
+                damerau_levenshtein_distance(typed, candidate)
+            };
+            let threshold = (typed.len().max(candidate.len()) / 3).max(1);
+            if distance <= threshold { Some((distance, candidate)) } else { None }
+        })
+        .min_by(|(dist_a, cand_a), (dist_b, cand_b)| {
+            dist_a.cmp(dist_b).then_with(|| {
+                let a_substr = is_case_insensitive_substring_match(typed, cand_a);
+                let b_substr = is_case_insensitive_substring_match(typed, cand_b);
+                // candidates that substring-match sort first (`false < true` otherwise, so flip it)
+                b_substr.cmp(&a_substr)
+            })
+        })
+        .map(|(_, candidate)| candidate)
+}
+
+/// Whether `a` and `b` are a case-insensitive substring match of each other, in either direction.
+fn is_case_insensitive_substring_match(a: &str, b: &str) -> bool {
+    let (a, b) = (a.to_ascii_lowercase(), b.to_ascii_lowercase());
+    a.contains(&b) || b.contains(&a)
 }
 
 
@@ -0,0 +1,268 @@
+use std::path::PathBuf;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity};
+use serde::Serialize;
+
+use crate::{
+    core::odoo::SyncOdoo,
+    threads::SessionInfo,
+};
+
+/// ANSI SGR codes used by the `Rich`/`Medium` renderers. Centralized here so `--no-color` can
+/// blank every one of them out in one place instead of threading a flag through each `format!`.
+struct AnsiStyle {
+    red: &'static str,
+    yellow: &'static str,
+    blue: &'static str,
+    bold: &'static str,
+    dim: &'static str,
+    reset: &'static str,
+}
+
+impl AnsiStyle {
+    const COLOR: AnsiStyle = AnsiStyle {
+        red: "\x1b[31m",
+        yellow: "\x1b[33m",
+        blue: "\x1b[34m",
+        bold: "\x1b[1m",
+        dim: "\x1b[2m",
+        reset: "\x1b[0m",
+    };
+    const PLAIN: AnsiStyle = AnsiStyle { red: "", yellow: "", blue: "", bold: "", dim: "", reset: "" };
+
+    fn for_severity(&self, severity: Option<DiagnosticSeverity>) -> &'static str {
+        match severity {
+            Some(DiagnosticSeverity::ERROR) => self.red,
+            Some(DiagnosticSeverity::WARNING) => self.yellow,
+            _ => self.blue,
+        }
+    }
+}
+
+/// A single diagnostic finding, flattened for machine-readable reporting: the file it
+/// belongs to alongside the [`Diagnostic`] itself.
+pub struct LintFinding {
+    pub file_path: String,
+    pub diagnostic: Diagnostic,
+}
+
+/// Output format requested for a headless lint run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LintOutputFormat {
+    Json,
+    Sarif,
+    /// codespan-reporting-style: a multi-line source snippet per finding, with carets under the
+    /// span and a colorized severity label.
+    Rich,
+    /// One line per finding: `file:line:col: severity[code]: message`.
+    Medium,
+    /// `file:line:col: severity: message`, nothing else - for piping into an editor's quickfix list.
+    Short,
+}
+
+/// Drives a non-interactive lint pass over a set of paths: builds the session the same way
+/// the LSP entry point does, lets the normal diagnostic pipeline run to completion, then
+/// reports every finding through the requested emitter instead of publishing over stdio.
+///
+/// Returns the process exit code: non-zero when at least one `ERROR`-severity diagnostic
+/// was found, so this can be dropped straight into a pre-commit hook or CI job.
+pub struct BatchLintFeature;
+
+impl BatchLintFeature {
+    pub fn run(session: &mut SessionInfo, paths: &[PathBuf], format: LintOutputFormat, no_color: bool) -> i32 {
+        let findings = BatchLintFeature::collect_findings(session, paths);
+        let has_error = findings.iter().any(|f| f.diagnostic.severity == Some(DiagnosticSeverity::ERROR));
+
+        let report = match format {
+            LintOutputFormat::Json => BatchLintFeature::to_json(&findings),
+            LintOutputFormat::Sarif => BatchLintFeature::to_sarif(&findings),
+            LintOutputFormat::Rich => BatchLintFeature::to_rich(session, &findings, no_color),
+            LintOutputFormat::Medium => BatchLintFeature::to_medium(&findings, no_color),
+            LintOutputFormat::Short => BatchLintFeature::to_short(&findings),
+        };
+        println!("{}", report);
+
+        if has_error { 1 } else { 0 }
+    }
+
+    fn collect_findings(session: &mut SessionInfo, paths: &[PathBuf]) -> Vec<LintFinding> {
+        let mut findings = Vec::new();
+        for path in paths {
+            let path_str = path.to_string_lossy().to_string();
+            SyncOdoo::request_update_file_index(session, path, false);
+            for diagnostic in SyncOdoo::get_diagnostics_for_path(session, &path_str) {
+                findings.push(LintFinding { file_path: path_str.clone(), diagnostic });
+            }
+        }
+        findings
+    }
+
+    fn to_json(findings: &[LintFinding]) -> String {
+        #[derive(Serialize)]
+        struct JsonRecord<'a> {
+            file: &'a str,
+            line: u32,
+            column: u32,
+            end_line: u32,
+            end_column: u32,
+            code: Option<String>,
+            severity: &'static str,
+            message: &'a str,
+        }
+
+        let records: Vec<JsonRecord> = findings.iter().map(|f| JsonRecord {
+            file: &f.file_path,
+            line: f.diagnostic.range.start.line,
+            column: f.diagnostic.range.start.character,
+            end_line: f.diagnostic.range.end.line,
+            end_column: f.diagnostic.range.end.character,
+            code: f.diagnostic.code.as_ref().map(|c| BatchLintFeature::code_to_string(c)),
+            severity: BatchLintFeature::severity_to_str(f.diagnostic.severity),
+            message: &f.diagnostic.message,
+        }).collect();
+
+        serde_json::to_string_pretty(&records).unwrap_or_else(|_| "[]".to_string())
+    }
+
+    fn to_sarif(findings: &[LintFinding]) -> String {
+        let results: Vec<serde_json::Value> = findings.iter().map(|f| {
+            serde_json::json!({
+                "ruleId": f.diagnostic.code.as_ref().map(|c| BatchLintFeature::code_to_string(c)),
+                "level": BatchLintFeature::severity_to_sarif_level(f.diagnostic.severity),
+                "message": { "text": f.diagnostic.message },
+                "locations": [{
+                    "physicalLocation": {
+                        "artifactLocation": { "uri": f.file_path },
+                        "region": {
+                            "startLine": f.diagnostic.range.start.line + 1,
+                            "startColumn": f.diagnostic.range.start.character + 1,
+                            "endLine": f.diagnostic.range.end.line + 1,
+                            "endColumn": f.diagnostic.range.end.character + 1,
+                        }
+                    }
+                }]
+            })
+        }).collect();
+
+        let sarif = serde_json::json!({
+            "$schema": "https://raw.githubusercontent.com/oasis-tcs/sarif-spec/master/Schemata/sarif-schema-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": { "driver": { "name": "odoo-ls", "informationUri": "https://github.com/antoniodavid/odoo-ls" } },
+                "results": results,
+            }]
+        });
+        serde_json::to_string_pretty(&sarif).unwrap_or_else(|_| "{}".to_string())
+    }
+
+    /// `file:line:col: severity: message`, one line per finding, no color - for quickfix lists.
+    fn to_short(findings: &[LintFinding]) -> String {
+        findings.iter().map(|f| {
+            format!(
+                "{}:{}:{}: {}: {}",
+                f.file_path,
+                f.diagnostic.range.start.line + 1,
+                f.diagnostic.range.start.character + 1,
+                BatchLintFeature::severity_to_str(f.diagnostic.severity),
+                f.diagnostic.message,
+            )
+        }).collect::<Vec<_>>().join("\n")
+    }
+
+    /// One line per finding with the code included, optionally colorized by severity.
+    fn to_medium(findings: &[LintFinding], no_color: bool) -> String {
+        let style = if no_color { &AnsiStyle::PLAIN } else { &AnsiStyle::COLOR };
+        findings.iter().map(|f| {
+            let severity = BatchLintFeature::severity_to_str(f.diagnostic.severity);
+            let color = style.for_severity(f.diagnostic.severity);
+            let code = f.diagnostic.code.as_ref().map(|c| BatchLintFeature::code_to_string(c)).unwrap_or_default();
+            format!(
+                "{}:{}:{}: {}{}{}{}[{}]: {}",
+                f.file_path,
+                f.diagnostic.range.start.line + 1,
+                f.diagnostic.range.start.character + 1,
+                color, style.bold, severity, style.reset,
+                code,
+                f.diagnostic.message,
+            )
+        }).collect::<Vec<_>>().join("\n")
+    }
+
+    /// codespan-reporting-style rendering: the offending source line, with a caret span
+    /// underneath pointing at the diagnostic's range, above the severity-colored message.
+    fn to_rich(session: &mut SessionInfo, findings: &[LintFinding], no_color: bool) -> String {
+        let style = if no_color { &AnsiStyle::PLAIN } else { &AnsiStyle::COLOR };
+        let mut blocks = Vec::with_capacity(findings.len());
+
+        for f in findings {
+            let severity = BatchLintFeature::severity_to_str(f.diagnostic.severity);
+            let color = style.for_severity(f.diagnostic.severity);
+            let code = f.diagnostic.code.as_ref().map(|c| BatchLintFeature::code_to_string(c)).unwrap_or_default();
+            let line_no = f.diagnostic.range.start.line;
+            let col = f.diagnostic.range.start.character;
+
+            let mut block = format!(
+                "{}{}{}{}[{}]{}: {}\n  {}-->{} {}:{}:{}\n",
+                color, style.bold, severity, style.reset,
+                code, style.reset,
+                f.diagnostic.message,
+                style.blue, style.reset,
+                f.file_path, line_no + 1, col + 1,
+            );
+
+            if let Some(source_line) = BatchLintFeature::source_line(session, &f.file_path, line_no) {
+                let span = if f.diagnostic.range.end.line == line_no {
+                    f.diagnostic.range.end.character.saturating_sub(col).max(1)
+                } else {
+                    (source_line.chars().count() as u32).saturating_sub(col).max(1)
+                };
+                let caret = "^".repeat(span as usize);
+                let indent = " ".repeat(col as usize);
+                block.push_str(&format!(
+                    "   |\n{:>3} | {}\n   | {}{}{}{}{}\n",
+                    line_no + 1, source_line, indent, color, style.bold, caret, style.reset,
+                ));
+            }
+
+            blocks.push(block);
+        }
+
+        blocks.join("\n")
+    }
+
+    /// The 0-indexed `line_no`'th line of `path`'s current in-memory source, if the file is
+    /// still tracked by `FileMgr` - `None` degrades the `Rich` renderer to just the header line,
+    /// rather than failing the whole report over one file whose source isn't available anymore.
+    fn source_line(session: &mut SessionInfo, path: &str, line_no: u32) -> Option<String> {
+        let file_info = session.sync_odoo.get_file_mgr().borrow().get_file_info(&path.to_string())?;
+        let file_info = file_info.borrow();
+        let contents = file_info.file_info_ast.borrow().text_document.as_ref()?.contents().to_string();
+        contents.lines().nth(line_no as usize).map(str::to_string)
+    }
+
+    fn code_to_string(code: &lsp_types::NumberOrString) -> String {
+        match code {
+            lsp_types::NumberOrString::Number(n) => n.to_string(),
+            lsp_types::NumberOrString::String(s) => s.clone(),
+        }
+    }
+
+    fn severity_to_str(severity: Option<DiagnosticSeverity>) -> &'static str {
+        match severity {
+            Some(DiagnosticSeverity::ERROR) => "error",
+            Some(DiagnosticSeverity::WARNING) => "warning",
+            Some(DiagnosticSeverity::INFORMATION) => "information",
+            Some(DiagnosticSeverity::HINT) => "hint",
+            _ => "error",
+        }
+    }
+
+    fn severity_to_sarif_level(severity: Option<DiagnosticSeverity>) -> &'static str {
+        match severity {
+            Some(DiagnosticSeverity::ERROR) => "error",
+            Some(DiagnosticSeverity::WARNING) => "warning",
+            Some(DiagnosticSeverity::INFORMATION) | Some(DiagnosticSeverity::HINT) => "note",
+            _ => "error",
+        }
+    }
+}
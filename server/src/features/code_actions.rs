@@ -0,0 +1,134 @@
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, Range, TextEdit, Uri, WorkspaceEdit};
+use serde::{Deserialize, Serialize};
+
+use crate::core::diagnostics::DiagnosticCode;
+use crate::core::file_mgr::FileMgr;
+use crate::threads::SessionInfo;
+
+/// A machine-actionable description of how to fix a diagnostic, stashed on [`Diagnostic::data`]
+/// at the point the diagnostic is produced, so [`CodeActionFeature::get_code_actions`] can turn
+/// it straight into a [`WorkspaceEdit`] without re-parsing the file. Mirrors how rust-analyzer
+/// attaches fixes to diagnostics.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum QuickFixDescriptor {
+    /// Insert a missing required XML attribute at the given (zero-width) point, right after
+    /// the element's opening tag name.
+    InsertMissingAttribute { at: Range, attribute: String, value: String },
+    /// Replace an unresolved external-id reference with the closest declared id.
+    CorrectExternalId { range: Range, suggestion: String },
+    /// Remove a duplicate `<field>` declaration entirely.
+    RemoveDuplicateField { range: Range },
+    /// Replace an unresolved symbol reference (import submodule, model/field/method name, ...)
+    /// with the closest in-scope candidate found by [`crate::features::ast_utils`]'s
+    /// "did you mean" matcher.
+    RenameUnresolvedReference { range: Range, suggestion: String },
+    /// Insert a stub method definition at `insert_at` (the zero-width point right after the
+    /// class body's last statement, already indented one level past the `class` line) for a
+    /// `compute=`/`related=`/`inverse=`/`search=` string argument that names a method the model
+    /// doesn't have, registered against [`DiagnosticCode::OLS02001`].
+    CreateMissingMethod { insert_at: Range, indent: String, method_name: String },
+    /// Insert `@classmethod\n` immediately above a `def` line that's called as a classmethod
+    /// without being decorated as one, registered against [`DiagnosticCode::OLS01007`].
+    InsertClassmethodDecorator { at: Range, indent: String },
+}
+
+impl QuickFixDescriptor {
+    /// Wraps a diagnostic with this fix descriptor stored in its `data` field.
+    pub fn attach_to(self, mut diagnostic: Diagnostic) -> Diagnostic {
+        diagnostic.data = serde_json::to_value(&self).ok();
+        diagnostic
+    }
+}
+
+/// A registered code's fix: turns the [`QuickFixDescriptor`] already attached to one of its
+/// diagnostics into `(title, range, replacement text)`, the same shape [`CodeActionFeature::build_edit`]
+/// produces for the non-registered variants.
+type FixGenerator = fn(&QuickFixDescriptor) -> (String, Range, String);
+
+/// Maps an `OLS*` code to the generator that knows how to turn its [`QuickFixDescriptor`] into an
+/// edit. Registering a fix for a new code is adding one line here plus one `DiagnosticCode`
+/// variant - [`CodeActionFeature::get_code_actions`] itself never needs to change.
+fn fix_registry() -> &'static HashMap<&'static str, FixGenerator> {
+    static REGISTRY: OnceLock<HashMap<&'static str, FixGenerator>> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        let mut registry: HashMap<&'static str, FixGenerator> = HashMap::new();
+        registry.insert(DiagnosticCode::OLS02001.as_str(), create_missing_method_fix);
+        registry.insert(DiagnosticCode::OLS01007.as_str(), insert_classmethod_decorator_fix);
+        registry
+    })
+}
+
+fn create_missing_method_fix(fix: &QuickFixDescriptor) -> (String, Range, String) {
+    let QuickFixDescriptor::CreateMissingMethod { insert_at, indent, method_name } = fix else {
+        unreachable!("registered only for CreateMissingMethod")
+    };
+    (
+        format!("Create method `{}`", method_name),
+        *insert_at,
+        format!("\n{indent}def {method_name}(self):\n{indent}    pass\n"),
+    )
+}
+
+fn insert_classmethod_decorator_fix(fix: &QuickFixDescriptor) -> (String, Range, String) {
+    let QuickFixDescriptor::InsertClassmethodDecorator { at, indent } = fix else {
+        unreachable!("registered only for InsertClassmethodDecorator")
+    };
+    ("Add @classmethod".to_string(), *at, format!("{indent}@classmethod\n"))
+}
+
+pub struct CodeActionFeature {}
+
+impl CodeActionFeature {
+    /// Builds one `CodeActionKind::QUICKFIX` per diagnostic in `diagnostics` that carries a
+    /// [`QuickFixDescriptor`] in its `data` field - codes registered in [`fix_registry`] are
+    /// dispatched there, everything else falls back to [`CodeActionFeature::build_edit`]'s direct
+    /// match on the descriptor shape.
+    pub fn get_code_actions(_session: &mut SessionInfo, file_path: &str, diagnostics: &[Diagnostic]) -> Vec<CodeActionOrCommand> {
+        let uri = FileMgr::pathname2uri(file_path);
+        diagnostics.iter().filter_map(|diagnostic| {
+            let data = diagnostic.data.clone()?;
+            let fix: QuickFixDescriptor = serde_json::from_value(data).ok()?;
+            let code = diagnostic.code.as_ref().and_then(|code| match code {
+                lsp_types::NumberOrString::String(code) => Some(code.as_str()),
+                lsp_types::NumberOrString::Number(_) => None,
+            });
+            let (title, range, new_text) = match code.and_then(|code| fix_registry().get(code)) {
+                Some(generator) => generator(&fix),
+                None => CodeActionFeature::build_edit(&fix),
+            };
+            let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+            changes.insert(uri.clone(), vec![TextEdit { range, new_text }]);
+            Some(CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diagnostic.clone()]),
+                edit: Some(WorkspaceEdit { changes: Some(changes), ..Default::default() }),
+                is_preferred: Some(true),
+                ..Default::default()
+            }))
+        }).collect()
+    }
+
+    fn build_edit(fix: &QuickFixDescriptor) -> (String, Range, String) {
+        match fix {
+            QuickFixDescriptor::InsertMissingAttribute { at, attribute, value } => {
+                (format!("Insert missing attribute `{}`", attribute), *at, format!(" {}=\"{}\"", attribute, value))
+            }
+            QuickFixDescriptor::CorrectExternalId { range, suggestion } => {
+                (format!("Change to `{}`", suggestion), *range, suggestion.clone())
+            }
+            QuickFixDescriptor::RemoveDuplicateField { range } => {
+                ("Remove duplicate field".to_string(), *range, String::new())
+            }
+            QuickFixDescriptor::RenameUnresolvedReference { range, suggestion } => {
+                (format!("Rename to `{}`", suggestion), *range, suggestion.clone())
+            }
+            QuickFixDescriptor::CreateMissingMethod { .. } => create_missing_method_fix(fix),
+            QuickFixDescriptor::InsertClassmethodDecorator { .. } => insert_classmethod_decorator_fix(fix),
+        }
+    }
+}
@@ -1,11 +1,11 @@
 use lsp_types::{GotoDefinitionResponse, LocationLink, Range};
 use ruff_python_ast::{Expr, ExprCall};
-use ruff_text_size::TextSize;
+use ruff_text_size::{TextRange, TextSize};
 use std::path::PathBuf;
 use std::{cell::RefCell, rc::Rc};
 
 use crate::constants::{PackageType, SymType};
-use crate::core::evaluation::{Evaluation, EvaluationValue, ExprOrIdent};
+use crate::core::evaluation::{Context, ContextValue, Evaluation, EvaluationValue, ExprOrIdent};
 use crate::core::file_mgr::{FileInfo, FileMgr};
 use crate::core::odoo::SyncOdoo;
 use crate::core::python_odoo_builder::MAGIC_FIELDS;
@@ -21,6 +21,41 @@ pub struct DefinitionFeature {}
 
 impl DefinitionFeature {
 
+    /// Follows a field symbol's own evaluations to the `Field` subclass it's built from (e.g.
+    /// `Many2one`, `Char`), together with its `comodel_name` when the field is relational - the
+    /// same lookup `xml_completion::get_field_type` uses to show "(res.partner) Many2one" in
+    /// completion details.
+    fn resolve_field_class(session: &mut SessionInfo, field_symbol: &Rc<RefCell<Symbol>>) -> Option<(Rc<RefCell<Symbol>>, Option<String>)> {
+        let sym_ref = field_symbol.borrow();
+        let parent_context = sym_ref.parent().and_then(|parent| parent.upgrade());
+        let evals = sym_ref.evaluations().cloned()?;
+        drop(sym_ref);
+        for eval in evals.iter() {
+            let eval_symbol = eval.symbol.get_symbol(session, &mut None, &mut vec![], None);
+            let mut context = None;
+            if let Some(parent) = &parent_context {
+                context = Some(Context::new());
+                context.as_mut().unwrap().insert(oyarn!("base_attr").to_string(), ContextValue::SYMBOL(Rc::downgrade(parent)));
+            }
+            let eval_weaks = Symbol::follow_ref(&eval_symbol, session, &mut context, true, false, None);
+            for eval_weak in eval_weaks.iter() {
+                if let Some(field_class) = eval_weak.upgrade_weak() {
+                    if field_class.borrow().is_field_class(session) {
+                        let comodel_name = eval_weak.as_weak().context.get("comodel_name").map(|v| v.as_string());
+                        return Some((field_class, comodel_name));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Thin wrapper around [`DefinitionFeature::resolve_field_class`] for callers that only care
+    /// about the comodel, not the field class itself.
+    fn resolve_comodel_name(session: &mut SessionInfo, field_symbol: &Rc<RefCell<Symbol>>) -> Option<String> {
+        DefinitionFeature::resolve_field_class(session, field_symbol)?.1
+    }
+
     fn check_for_domain_field(session: &mut SessionInfo, eval: &Evaluation, file_symbol: &Rc<RefCell<Symbol>>, call_expr: &Option<ExprCall>, offset: usize, links: &mut Vec<LocationLink>) -> bool {
         let (field_name, field_range) = if let Some(eval_value) = eval.value.as_ref() {
             if let EvaluationValue::CONSTANT(Expr::StringLiteral(expr)) = eval_value {
@@ -33,22 +68,75 @@ impl DefinitionFeature {
         };
         let Some(call_expr) = call_expr else { return false };
         let module = file_symbol.borrow().find_module();
-        let string_domain_fields = FeaturesUtils::find_argument_symbols(
-            session, Symbol::get_scope_symbol(file_symbol.clone(), offset as u32, false), module, &field_name, call_expr, offset, field_range
+        let scope = Symbol::get_scope_symbol(file_symbol.clone(), offset as u32, false);
+
+        if !field_name.contains('.') {
+            let string_domain_fields = FeaturesUtils::find_argument_symbols(
+                session, scope, module, &field_name, call_expr, offset, field_range
+            );
+            string_domain_fields.iter().for_each(|(field, field_range)|{
+                if let Some(file_sym) = field.borrow().get_file().and_then(|file_sym_weak| file_sym_weak.upgrade()){
+                    let path = file_sym.borrow().paths()[0].clone();
+                    let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &field.borrow().range());
+                    links.push(LocationLink{
+                        origin_selection_range: Some(session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, file_symbol.borrow().paths().first().as_ref().unwrap(), &field_range)),
+                        target_uri: FileMgr::pathname2uri(&path),
+                        target_selection_range: range,
+                        target_range: range,
+                    });
+                }
+            });
+            return string_domain_fields.len() > 0;
+        }
+
+        // Dotted relational path (e.g. `partner_id.country_id.name`): resolve the first segment
+        // the usual way, then hop from field to comodel for every following segment, stopping as
+        // soon as a segment isn't a relational field.
+        let segments: Vec<&str> = field_name.split('.').collect();
+        let first_segment_range = TextRange::new(field_range.start(), field_range.start() + TextSize::try_from(segments[0].len()).unwrap());
+        let first_hits = FeaturesUtils::find_argument_symbols(
+            session, scope, module.clone(), segments[0], call_expr, offset, first_segment_range
         );
-        string_domain_fields.iter().for_each(|(field, field_range)|{
-            if let Some(file_sym) = field.borrow().get_file().and_then(|file_sym_weak| file_sym_weak.upgrade()){
-                let path = file_sym.borrow().paths()[0].clone();
-                let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &field.borrow().range());
-                links.push(LocationLink{
-                    origin_selection_range: Some(session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, file_symbol.borrow().paths().first().as_ref().unwrap(), &field_range)),
-                    target_uri: FileMgr::pathname2uri(&path),
-                    target_selection_range: range,
-                    target_range: range,
-                });
+        let mut found_any = false;
+        for (first_field, _) in first_hits.iter() {
+            let mut current_field = first_field.clone();
+            let mut byte_offset = 0usize;
+            for (seg_index, segment) in segments.iter().enumerate() {
+                let seg_start = field_range.start() + TextSize::try_from(byte_offset).unwrap();
+                let seg_range = TextRange::new(seg_start, seg_start + TextSize::try_from(segment.len()).unwrap());
+                byte_offset += segment.len() + 1; // account for the dot separator
+                if seg_index > 0 {
+                    let Some(comodel_name) = DefinitionFeature::resolve_comodel_name(session, &current_field) else {
+                        break; // previous segment wasn't a relational field: stop mid-path
+                    };
+                    let Some(model) = session.sync_odoo.models.get(&oyarn!("{}", comodel_name)).cloned() else {
+                        break;
+                    };
+                    let mut next_field = None;
+                    for class_symbol_rc in model.borrow().get_symbols(session, module.clone()) {
+                        let (field_symbols, _) = class_symbol_rc.borrow().get_member_symbol(session, &S!(*segment), module.clone(), false, false, true, true, false);
+                        if let Some(symbol) = field_symbols.into_iter().next() {
+                            next_field = Some(symbol);
+                            break;
+                        }
+                    }
+                    let Some(next_field) = next_field else { break };
+                    current_field = next_field;
+                }
+                if let Some(file_sym) = current_field.borrow().get_file().and_then(|file_sym_weak| file_sym_weak.upgrade()) {
+                    let path = file_sym.borrow().paths()[0].clone();
+                    let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &current_field.borrow().range());
+                    links.push(LocationLink{
+                        origin_selection_range: Some(session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, file_symbol.borrow().paths().first().as_ref().unwrap(), &seg_range)),
+                        target_uri: FileMgr::pathname2uri(&path),
+                        target_selection_range: range,
+                        target_range: range,
+                    });
+                    found_any = true;
+                }
             }
-        });
-        string_domain_fields.len() > 0
+        }
+        found_any
     }
 
     fn check_for_model_string(session: &mut SessionInfo, eval: &Evaluation, file_symbol: &Rc<RefCell<Symbol>>, links: &mut Vec<LocationLink>) -> bool {
@@ -389,13 +477,163 @@ impl DefinitionFeature {
         None
     }
 
-    pub fn get_location_csv(_session: &mut SessionInfo,
-        _file_symbol: &Rc<RefCell<Symbol>>,
-        _file_info: &Rc<RefCell<FileInfo>>,
-        _line: u32,
-        _character: u32
-    ) -> Option<GotoDefinitionResponse> {
+    /// Splits the raw text of `row_range` on commas and returns the `(column_index, cell_range)`
+    /// the cursor `offset` falls into. `CsvRecord`/`IndexedCsv::header_range` only keep the row's
+    /// overall byte range, so cell boundaries have to be recomputed on demand rather than stored
+    /// up front for every row.
+    fn find_csv_cell(source: &str, row_range: &std::ops::Range<usize>, offset: usize) -> Option<(usize, std::ops::Range<usize>)> {
+        if !row_range.contains(&offset) {
+            return None;
+        }
+        let row = &source[row_range.clone()];
+        let mut cell_start = row_range.start;
+        for (index, cell) in row.split(',').enumerate() {
+            let cell_range = cell_start..cell_start + cell.len();
+            if cell_range.contains(&offset) || offset == cell_range.end {
+                return Some((index, cell_range));
+            }
+            cell_start = cell_range.end + 1; // skip the separating comma
+        }
         None
     }
 
+    /// Odoo CSV data files (`ir.model.access.csv`, translation/import CSVs, ...) name their
+    /// target model after the filename and their columns after its fields, with a `:id` suffix
+    /// marking a column (or the whole `id` column) as an external id rather than a plain value.
+    /// The header row resolves to field symbols on that model, and cells under a `:id` column
+    /// resolve to the record they reference, the same way `check_for_xml_id_string` does for
+    /// Python/XML string literals.
+    pub fn get_location_csv(session: &mut SessionInfo,
+        file_symbol: &Rc<RefCell<Symbol>>,
+        file_info: &Rc<RefCell<FileInfo>>,
+        line: u32,
+        character: u32
+    ) -> Option<GotoDefinitionResponse> {
+        let offset = file_info.borrow().position_to_offset(line, character, session.sync_odoo.encoding);
+        let file_info_ast = file_info.borrow().file_info_ast.clone();
+        let fia = file_info_ast.borrow();
+        let indexed_csv = fia.indexed_csv.as_ref()?;
+        if indexed_csv.header.is_empty() {
+            return None;
+        }
+        let source = fia.text_document.as_ref()?.contents().to_string();
+        let header = indexed_csv.header.clone();
+        let header_range = indexed_csv.header_range.clone();
+        let record = indexed_csv.records.iter().find(|r| r.range.contains(&offset)).cloned();
+        drop(fia);
+
+        let file_path = file_symbol.borrow().paths().first().cloned()?;
+        let model_name = PathBuf::from(&file_path).file_stem()?.to_str()?.to_string();
+        let model = session.sync_odoo.models.get(&oyarn!("{}", model_name)).cloned()?;
+        let from_module = file_symbol.borrow().find_module();
+
+        if let Some((column, cell_range)) = header_range.as_ref().and_then(|header_range| DefinitionFeature::find_csv_cell(&source, header_range, offset)) {
+            let column_name = header.get(column)?;
+            let field_name = column_name.strip_suffix(":id").unwrap_or(column_name);
+            if field_name == "id" {
+                // The bare `id` column declares this row's own external id, it isn't a reference
+                // to a field.
+                return None;
+            }
+            let origin_range = session.sync_odoo.get_file_mgr().borrow().std_range_to_range(session, &file_path, &cell_range);
+            let mut links = vec![];
+            for class_symbol_rc in model.borrow().get_symbols(session, from_module.clone()) {
+                let (field_symbols, _) = class_symbol_rc.borrow().get_member_symbol(session, &S!(field_name), from_module.clone(), false, false, true, true, false);
+                for field_symbol in field_symbols {
+                    if let Some(file) = field_symbol.borrow().get_file().and_then(|f| f.upgrade()) {
+                        let path = file.borrow().paths()[0].clone();
+                        let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &field_symbol.borrow().range());
+                        links.push(LocationLink {
+                            origin_selection_range: Some(origin_range),
+                            target_uri: FileMgr::pathname2uri(&path),
+                            target_selection_range: range,
+                            target_range: range,
+                        });
+                    }
+                }
+            }
+            return Some(GotoDefinitionResponse::Link(links));
+        }
+
+        let record = record?;
+        let (column, cell_range) = DefinitionFeature::find_csv_cell(&source, &record.range, offset)?;
+        let column_name = header.get(column)?;
+        column_name.strip_suffix(":id")?; // only `:id` columns reference another record
+        let cell_value = record.fields.get(column)?.trim();
+        if cell_value.is_empty() {
+            return None;
+        }
+        let origin_range = session.sync_odoo.get_file_mgr().borrow().std_range_to_range(session, &file_path, &cell_range);
+        let mut links = vec![];
+        let xml_ids = SyncOdoo::get_xml_ids(session, file_symbol, cell_value, &std::ops::Range{start: 0, end: 0}, &mut vec![]);
+        for xml_id in xml_ids {
+            let file = xml_id.get_file_symbol();
+            if let Some(file) = file {
+                if let Some(file) = file.upgrade() {
+                    let range = session.sync_odoo.get_file_mgr().borrow().std_range_to_range(session, &file.borrow().paths()[0], &xml_id.get_range());
+                    links.push(LocationLink {
+                        origin_selection_range: Some(origin_range),
+                        target_uri: FileMgr::pathname2uri(&file.borrow().paths()[0]),
+                        target_range: range,
+                        target_selection_range: range });
+                }
+            }
+        }
+        Some(GotoDefinitionResponse::Link(links))
+    }
+
+    /// `textDocument/typeDefinition` for a relational field: jump straight to the comodel
+    /// class(es) instead of the field's own declaration. Non-relational fields fall back to the
+    /// field's declared Python type (`Integer`, `Char`, ...).
+    pub fn get_type_location(session: &mut SessionInfo,
+        file_symbol: &Rc<RefCell<Symbol>>,
+        file_info: &Rc<RefCell<FileInfo>>,
+        line: u32,
+        character: u32
+    ) -> Option<GotoDefinitionResponse> {
+        let offset = file_info.borrow().position_to_offset(line, character, session.sync_odoo.encoding);
+        let file_info_ast_clone = file_info.borrow().file_info_ast.clone();
+        let file_info_ast_ref = file_info_ast_clone.borrow();
+        let (analyse_ast_result, _range, _expr, _call_expr) = AstUtils::get_symbols(session, &file_info_ast_ref, file_symbol, offset as u32);
+        drop(file_info_ast_ref);
+        if analyse_ast_result.evaluations.is_empty() {
+            return None;
+        }
+        let from_module = file_symbol.borrow().find_module();
+        let mut links = vec![];
+        for eval in analyse_ast_result.evaluations.iter() {
+            let Some(field_symbol) = eval.symbol.get_symbol_as_weak(session, &mut None, &mut vec![], None).weak.upgrade() else { continue };
+            if field_symbol.borrow().typ() != SymType::VARIABLE || !field_symbol.borrow().is_field(session) {
+                continue;
+            }
+            let Some((field_class, comodel_name)) = DefinitionFeature::resolve_field_class(session, &field_symbol) else { continue };
+            if let Some(comodel_name) = comodel_name {
+                let Some(model) = session.sync_odoo.models.get(&oyarn!("{}", comodel_name)).cloned() else { continue };
+                for class_symbol_rc in model.borrow().get_symbols(session, from_module.clone()) {
+                    let class_symbol = class_symbol_rc.borrow();
+                    if let Some(model_file_sym) = class_symbol.get_file().and_then(|file_sym_weak| file_sym_weak.upgrade()) {
+                        let path = model_file_sym.borrow().get_symbol_first_path();
+                        let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &class_symbol.range());
+                        links.push(LocationLink{
+                            origin_selection_range: None,
+                            target_uri: FileMgr::pathname2uri(&path),
+                            target_selection_range: range,
+                            target_range: range,
+                        });
+                    }
+                }
+            } else if let Some(file) = field_class.borrow().get_file().and_then(|file_sym_weak| file_sym_weak.upgrade()) {
+                let path = file.borrow().get_symbol_first_path();
+                let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &field_class.borrow().range());
+                links.push(LocationLink{
+                    origin_selection_range: None,
+                    target_uri: FileMgr::pathname2uri(&path),
+                    target_selection_range: range,
+                    target_range: range,
+                });
+            }
+        }
+        if links.is_empty() { None } else { Some(GotoDefinitionResponse::Link(links)) }
+    }
+
 }
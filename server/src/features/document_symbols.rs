@@ -0,0 +1,94 @@
+use std::{cell::RefCell, rc::Rc};
+
+use lsp_types::DocumentSymbol;
+use ruff_text_size::{TextRange, TextSize};
+
+use crate::{constants::{PackageType, SymType}, core::symbols::symbol::Symbol, threads::SessionInfo};
+
+pub struct DocumentSymbolFeature;
+
+impl DocumentSymbolFeature {
+
+    /// Answers `textDocument/documentSymbol` for `file_symbol`: unlike [`super::workspace_symbols::WorkspaceSymbolFeature`],
+    /// which flattens everything into `SymbolInformation`-style entries, this preserves the
+    /// containment structure so editors can render a collapsible class/method/field outline.
+    pub fn get_document_symbols(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>) -> Option<Vec<DocumentSymbol>> {
+        let path = file_symbol.borrow().paths().first().cloned()?;
+        let children: Vec<DocumentSymbol> = file_symbol.borrow().all_symbols()
+            .filter_map(|sym| DocumentSymbolFeature::to_document_symbol(session, &sym, &path))
+            .collect();
+        if children.is_empty() {
+            None
+        } else {
+            Some(children)
+        }
+    }
+
+    /// Builds the `DocumentSymbol` for `symbol` (and, recursively, its own children), or `None`
+    /// for the same kinds `WorkspaceSymbolFeature` already skips: `SymType::VARIABLE` noise and
+    /// anything that has no resolvable range to anchor an outline entry on.
+    fn to_document_symbol(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>, path: &String) -> Option<DocumentSymbol> {
+        let symbol_borrowed = symbol.borrow();
+        if symbol_borrowed.typ() == SymType::VARIABLE || !symbol_borrowed.has_range() {
+            return None;
+        }
+
+        let file_info = session.sync_odoo.get_file_mgr().borrow().get_file_info(path);
+        let file_info = file_info?;
+        let range = file_info.borrow().text_range_to_range(&symbol_borrowed.range(), session.sync_odoo.encoding);
+        let selection_range = symbol_borrowed.name_range()
+            .map(|r| file_info.borrow().text_range_to_range(&r, session.sync_odoo.encoding))
+            .unwrap_or(range);
+
+        let mut children: Vec<DocumentSymbol> = symbol_borrowed.all_symbols()
+            .filter_map(|child| DocumentSymbolFeature::to_document_symbol(session, &child, path))
+            .collect();
+
+        if symbol_borrowed.typ() == SymType::PACKAGE(PackageType::MODULE) {
+            children.extend(DocumentSymbolFeature::xml_id_symbols(session, &symbol_borrowed));
+        }
+
+        #[allow(deprecated)]
+        Some(DocumentSymbol {
+            name: symbol_borrowed.name().to_string(),
+            detail: None,
+            kind: symbol_borrowed.get_lsp_symbol_kind(),
+            tags: None,
+            deprecated: None,
+            range,
+            selection_range,
+            children: if children.is_empty() { None } else { Some(children) },
+        })
+    }
+
+    /// Builds one `DocumentSymbol` per external id declared by a `PackageType::MODULE`'s
+    /// `xml_id_locations`, mirroring the `xmlid.`-prefixed entries `WorkspaceSymbolFeature` adds -
+    /// except here they're anchored to the module's own outline node instead of prefixed by name,
+    /// since containment already conveys which module they belong to.
+    fn xml_id_symbols(session: &mut SessionInfo, module_symbol: &std::cell::Ref<Symbol>) -> Vec<DocumentSymbol> {
+        let module = module_symbol.as_module_package();
+        let mut out = Vec::new();
+        for xml_id_name in module.xml_id_locations.keys() {
+            for data in module.get_xml_id(xml_id_name) {
+                let Some(xml_file_symbol) = data.get_xml_file_symbol() else { continue };
+                let Some(xml_path) = xml_file_symbol.borrow().paths().first().cloned() else { continue };
+                let Some(xml_file_info) = session.sync_odoo.get_file_mgr().borrow().get_file_info(&xml_path) else { continue };
+                let raw_range = data.get_range();
+                let text_range = TextRange::new(TextSize::new(raw_range.start as u32), TextSize::new(raw_range.end as u32));
+                let range = xml_file_info.borrow().text_range_to_range(&text_range, session.sync_odoo.encoding);
+                #[allow(deprecated)]
+                out.push(DocumentSymbol {
+                    name: xml_id_name.to_string(),
+                    detail: None,
+                    kind: xml_file_symbol.borrow().get_lsp_symbol_kind(),
+                    tags: None,
+                    deprecated: None,
+                    range,
+                    selection_range: range,
+                    children: None,
+                });
+            }
+        }
+        out
+    }
+}
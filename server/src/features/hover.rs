@@ -0,0 +1,100 @@
+use std::{cell::RefCell, rc::Rc, sync::LazyLock};
+
+use lsp_types::{Hover, HoverContents, MarkupContent, MarkupKind};
+use regex::Regex;
+use ruff_text_size::Ranged;
+
+use crate::{
+    core::{file_mgr::{FileInfo, FileMgr}, symbols::symbol::Symbol},
+    features::ast_utils::AstUtils,
+    threads::SessionInfo,
+    S,
+};
+
+/// Matches `:class:\`Name\``, `:meth:\`name\`` and bare backtick-quoted dotted names inside a
+/// docstring, so they can be turned into clickable cross-references.
+static DOC_XREF_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?::(?:class|meth|func|attr):)?`([A-Za-z_][A-Za-z0-9_.]*)`").unwrap()
+});
+
+pub struct HoverFeature;
+
+impl HoverFeature {
+    pub fn get_hover(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, line: u32, character: u32) -> Option<Hover> {
+        let offset = file_info.borrow().position_to_offset(line, character, session.sync_odoo.encoding);
+        let file_info_ast = file_info.borrow().file_info_ast.clone();
+        let file_info_ast_borrow = file_info_ast.borrow();
+        let (analyse_ast_result, _range, _expr, _call_expr) = AstUtils::get_symbols(session, &file_info_ast_borrow, file_symbol, offset as u32);
+        drop(file_info_ast_borrow);
+
+        let eval = analyse_ast_result.evaluations.first()?;
+        let target_symbol = eval.symbol.get_symbol_as_weak(session, &mut None, &mut vec![], None);
+        let target_symbol_rc = target_symbol.weak.upgrade()?;
+
+        let markdown = HoverFeature::build_markdown(session, &target_symbol_rc)?;
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent { kind: MarkupKind::Markdown, value: markdown }),
+            range: None,
+        })
+    }
+
+    fn build_markdown(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>) -> Option<String> {
+        let doc_string = HoverFeature::doc_string_of(symbol)?;
+        let mut markdown = String::new();
+        let mut last_end = 0;
+        for caps in DOC_XREF_REGEX.captures_iter(&doc_string) {
+            let whole = caps.get(0).unwrap();
+            let name = caps.get(1).unwrap().as_str();
+            markdown.push_str(&doc_string[last_end..whole.start()]);
+            match HoverFeature::resolve_cross_reference(session, symbol, name) {
+                Some(target) => {
+                    if let Some(path) = target.borrow().paths().first().cloned() {
+                        let uri = FileMgr::pathname2uri(&path);
+                        markdown.push_str(&format!("[`{}`]({})", name, uri.as_str()));
+                    } else {
+                        markdown.push_str(&format!("`{}`", name));
+                    }
+                }
+                None => markdown.push_str(&format!("`{}`", name)),
+            }
+            last_end = whole.end();
+        }
+        markdown.push_str(&doc_string[last_end..]);
+        Some(markdown)
+    }
+
+    fn doc_string_of(symbol: &Rc<RefCell<Symbol>>) -> Option<String> {
+        let sym_ref = symbol.borrow();
+        match sym_ref.typ() {
+            crate::constants::SymType::CLASS => sym_ref.as_class_sym().doc_string.clone(),
+            crate::constants::SymType::FUNCTION => sym_ref.as_func().doc_string.clone(),
+            _ => None,
+        }
+    }
+
+    /// Resolves a dotted cross-reference found inside a docstring the same way a real reference
+    /// at that position in the file would be resolved: the first segment goes through
+    /// `Symbol::infer_name` against the scope built for `symbol`'s own position (so imports,
+    /// outer scopes and shadowing are accounted for, not just `symbol`'s immediate siblings), and
+    /// each further dotted segment descends through `get_member_symbol` the same way attribute
+    /// access on a resolved symbol does elsewhere in this codebase.
+    fn resolve_cross_reference(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>, dotted_name: &str) -> Option<Rc<RefCell<Symbol>>> {
+        let file_symbol = symbol.borrow().get_file()?.upgrade()?;
+        let offset = symbol.borrow().range().start().to_u32();
+
+        let scope = Symbol::get_scope_symbol(file_symbol.clone(), offset, false);
+        AstUtils::build_scope(session, &scope);
+
+        let mut parts = dotted_name.split('.');
+        let first = parts.next()?;
+        let inferred = Symbol::infer_name(&mut session.sync_odoo, &scope, first, Some(offset));
+        let mut current = inferred.symbols.into_iter().next()?;
+
+        let from_module = file_symbol.borrow().find_module();
+        for part in parts {
+            let (members, _) = current.borrow().get_member_symbol(session, &S!(part), from_module.clone(), false, false, true, true, false);
+            current = members.into_iter().next()?;
+        }
+        Some(current)
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashSet;
+use std::{cell::RefCell, rc::Rc};
+
+use lsp_types::{InlayHint, InlayHintKind, InlayHintLabel, Position};
+use ruff_python_ast::visitor::{walk_stmt, Visitor};
+use ruff_python_ast::{Expr, Parameter, Stmt};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::core::evaluation::ExprOrIdent;
+use crate::core::file_mgr::FileInfo;
+use crate::core::symbols::symbol::Symbol;
+use crate::constants::SymType;
+use crate::features::ast_utils::AstUtils;
+use crate::threads::SessionInfo;
+
+pub struct InlayHintFeature;
+
+impl InlayHintFeature {
+    /// Collects every local binding (assignment target, annotation-less parameter, `for`-loop
+    /// target, `with ... as` target) within `range`, infers its type through the same evaluation
+    /// machinery `get_symbol_from_expr` drives, and emits an inlay hint right after the binding's
+    /// identifier (e.g. `rec: res.partner`).
+    pub fn get_inlay_hints(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, range: TextRange) -> Vec<InlayHint> {
+        let file_info_ast = file_info.borrow().file_info_ast.clone();
+        let file_info_ast_borrow = file_info_ast.borrow();
+        let Some(stmts) = file_info_ast_borrow.get_stmts() else { return vec![] };
+
+        let mut bindings = Vec::new();
+        for stmt in stmts.iter() {
+            let mut visitor = BindingFinderVisitor { range, bindings: Vec::new() };
+            visitor.visit_stmt(stmt);
+            bindings.append(&mut visitor.bindings);
+        }
+
+        let mut hints = Vec::new();
+        let mut seen_positions = HashSet::new();
+        for expr in bindings {
+            let offset = expr.range().end();
+            let (result, _) = AstUtils::get_symbol_from_expr(session, file_symbol, &expr, offset.to_u32());
+            let Some(eval) = result.evaluations.first() else { continue };
+            let target_symbol = eval.symbol.get_symbol_as_weak(session, &mut None, &mut vec![], None);
+            let Some(target_symbol_rc) = target_symbol.weak.upgrade() else { continue };
+            let Some(type_name) = InlayHintFeature::type_name_for_symbol(&target_symbol_rc) else { continue };
+
+            let path = file_symbol.borrow().paths().first().cloned();
+            let Some(path) = path else { continue };
+            let position = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path, &expr.range()).end;
+            if !seen_positions.insert((position.line, position.character)) {
+                continue;
+            }
+            hints.push(InlayHint {
+                position,
+                label: InlayHintLabel::String(format!(": {}", type_name)),
+                kind: Some(InlayHintKind::TYPE),
+                text_edits: None,
+                tooltip: None,
+                padding_left: None,
+                padding_right: None,
+                data: None,
+            });
+        }
+        hints
+    }
+
+    fn type_name_for_symbol(symbol: &Rc<RefCell<Symbol>>) -> Option<String> {
+        let sym_ref = symbol.borrow();
+        if sym_ref.typ() == SymType::CLASS {
+            if let Some(model) = sym_ref.as_class_sym()._model.as_ref() {
+                return Some(model.name.to_string());
+            }
+        }
+        Some(sym_ref.name().to_string())
+    }
+}
+
+struct BindingFinderVisitor<'a> {
+    range: TextRange,
+    bindings: Vec<ExprOrIdent<'a>>,
+}
+
+impl<'a> BindingFinderVisitor<'a> {
+    fn push_if_in_range(&mut self, expr: &'a Expr) {
+        if matches!(expr, Expr::Name(_)) && self.range.contains_range(expr.range()) {
+            self.bindings.push(ExprOrIdent::Expr(expr));
+        }
+    }
+}
+
+impl<'a> Visitor<'a> for BindingFinderVisitor<'a> {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        match stmt {
+            Stmt::Assign(assign) => {
+                for target in assign.targets.iter() {
+                    self.push_if_in_range(target);
+                }
+            }
+            Stmt::For(for_stmt) => {
+                self.push_if_in_range(&for_stmt.target);
+            }
+            Stmt::With(with_stmt) => {
+                for item in with_stmt.items.iter() {
+                    if let Some(vars) = item.optional_vars.as_ref() {
+                        self.push_if_in_range(vars);
+                    }
+                }
+            }
+            _ => {}
+        }
+        walk_stmt(self, stmt);
+    }
+
+    fn visit_parameter(&mut self, parameter: &'a Parameter) {
+        if parameter.annotation.is_none() && self.range.contains_range(parameter.name.range()) {
+            self.bindings.push(ExprOrIdent::Parameter(parameter));
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use std::collections::HashMap;
+use std::{cell::RefCell, rc::Rc};
+
+use lsp_types::{CodeAction, CodeActionKind, CodeActionOrCommand, Diagnostic, NumberOrString, Range, TextEdit, Uri, WorkspaceEdit};
+use ruff_python_ast::visitor::{walk_stmt, Visitor};
+use ruff_python_ast::Stmt;
+use ruff_text_size::{Ranged, TextSize};
+
+use crate::core::file_mgr::{combine_noqa_info, FileInfo, FileMgr, NoqaInfo};
+use crate::threads::SessionInfo;
+
+pub struct NoqaActionFeature;
+
+impl NoqaActionFeature {
+    /// Offers, for every diagnostic that carries a `code`, a line-level quick fix that inserts
+    /// (or extends, via [`combine_noqa_info`]) a `# odools: noqa: <CODE>` comment on that line,
+    /// and a block-level quick fix that suppresses the code for the enclosing `class`/`def`.
+    pub fn get_noqa_actions(session: &mut SessionInfo, file_info: &Rc<RefCell<FileInfo>>, file_path: &str, diagnostics: &[Diagnostic]) -> Vec<CodeActionOrCommand> {
+        let uri = FileMgr::pathname2uri(file_path);
+        let mut actions = Vec::new();
+        for diagnostic in diagnostics {
+            let Some(code) = NoqaActionFeature::code_string(diagnostic) else { continue };
+            if let Some(action) = NoqaActionFeature::line_noqa_action(session, file_info, &uri, diagnostic, &code) {
+                actions.push(action);
+            }
+            if let Some(action) = NoqaActionFeature::block_noqa_action(session, file_info, &uri, diagnostic, &code) {
+                actions.push(action);
+            }
+        }
+        actions
+    }
+
+    fn code_string(diagnostic: &Diagnostic) -> Option<String> {
+        match diagnostic.code.as_ref()? {
+            NumberOrString::Number(n) => Some(n.to_string()),
+            NumberOrString::String(s) => Some(s.clone()),
+        }
+    }
+
+    fn render_noqa_comment(info: &NoqaInfo) -> String {
+        match info {
+            NoqaInfo::Codes(codes) => format!("# odools: noqa: {}", codes.join(", ")),
+            NoqaInfo::All | NoqaInfo::None => "# odools: noqa".to_string(),
+        }
+    }
+
+    fn line_noqa_action(session: &mut SessionInfo, file_info: &Rc<RefCell<FileInfo>>, uri: &Uri, diagnostic: &Diagnostic, code: &str) -> Option<CodeActionOrCommand> {
+        let encoding = session.sync_odoo.encoding;
+        let file_info_ref = file_info.borrow();
+        let line = diagnostic.range.start.line;
+        let existing = file_info_ref.get_noqa_line(line);
+        let combined = combine_noqa_info(&vec![existing.clone().unwrap_or(NoqaInfo::None), NoqaInfo::Codes(vec![code.to_string()])]);
+        let comment_text = NoqaActionFeature::render_noqa_comment(&combined);
+
+        let edit = if let (Some(_), Some(range)) = (&existing, file_info_ref.get_noqa_line_range(line)) {
+            let start = file_info_ref.offset_to_position(range.start().to_u32(), encoding);
+            let end = file_info_ref.offset_to_position(range.end().to_u32(), encoding);
+            TextEdit { range: Range { start, end }, new_text: comment_text }
+        } else {
+            let end_offset = file_info_ref.line_end_offset(line, encoding)?;
+            let position = file_info_ref.offset_to_position(end_offset, encoding);
+            TextEdit { range: Range { start: position, end: position }, new_text: format!("  {}", comment_text) }
+        };
+
+        Some(NoqaActionFeature::build_action(uri, diagnostic, format!("Suppress `{}` with a noqa comment", code), edit))
+    }
+
+    /// Finds the innermost enclosing `class`/`def` for the diagnostic and inserts a block-level
+    /// `# odools: noqa` comment on its own line right before it, matching its indentation -
+    /// mirroring how `extract_tokens` keys `noqas_blocs` by the statement's own start offset.
+    fn block_noqa_action(session: &mut SessionInfo, file_info: &Rc<RefCell<FileInfo>>, uri: &Uri, diagnostic: &Diagnostic, code: &str) -> Option<CodeActionOrCommand> {
+        let encoding = session.sync_odoo.encoding;
+        let file_info_ref = file_info.borrow();
+        let offset = file_info_ref.position_to_offset(diagnostic.range.start.line, diagnostic.range.start.character, encoding) as u32;
+
+        let file_info_ast = file_info_ref.file_info_ast.clone();
+        let file_info_ast_ref = file_info_ast.borrow();
+        let stmts = file_info_ast_ref.get_stmts()?;
+        let mut visitor = EnclosingDefVisitor { offset: TextSize::from(offset), best: None };
+        for stmt in stmts.iter() {
+            visitor.visit_stmt(stmt);
+        }
+        let stmt_start = visitor.best?.start().to_u32();
+        drop(file_info_ast_ref);
+
+        let stmt_start_pos = file_info_ref.offset_to_position(stmt_start, encoding);
+        let line_start_offset = file_info_ref.position_to_offset(stmt_start_pos.line, 0, encoding) as u32;
+        let indent_fia = file_info_ref.file_info_ast.clone();
+        let indent_fia_ref = indent_fia.borrow();
+        let text_document = indent_fia_ref.text_document.as_ref()?;
+        let indent: String = text_document.contents()[line_start_offset as usize..stmt_start as usize].to_string();
+        drop(indent_fia_ref);
+
+        let position = file_info_ref.offset_to_position(stmt_start, encoding);
+        let edit = TextEdit {
+            range: Range { start: position, end: position },
+            new_text: format!("# odools: noqa: {}\n{}", code, indent),
+        };
+
+        Some(NoqaActionFeature::build_action(uri, diagnostic, format!("Suppress `{}` for the enclosing block", code), edit))
+    }
+
+    fn build_action(uri: &Uri, diagnostic: &Diagnostic, title: String, edit: TextEdit) -> CodeActionOrCommand {
+        let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
+        changes.insert(uri.clone(), vec![edit]);
+        CodeActionOrCommand::CodeAction(CodeAction {
+            title,
+            kind: Some(CodeActionKind::QUICKFIX),
+            diagnostics: Some(vec![diagnostic.clone()]),
+            edit: Some(WorkspaceEdit { changes: Some(changes), ..Default::default() }),
+            is_preferred: Some(false),
+            ..Default::default()
+        })
+    }
+}
+
+struct EnclosingDefVisitor {
+    offset: TextSize,
+    best: Option<ruff_text_size::TextRange>,
+}
+
+impl<'a> Visitor<'a> for EnclosingDefVisitor {
+    fn visit_stmt(&mut self, stmt: &'a Stmt) {
+        if matches!(stmt, Stmt::ClassDef(_) | Stmt::FunctionDef(_)) && stmt.range().contains(self.offset) {
+            self.best = Some(stmt.range());
+        }
+        walk_stmt(self, stmt);
+    }
+}
@@ -5,28 +5,47 @@ use ruff_python_ast::visitor::{walk_expr, walk_stmt, Visitor};
 use ruff_python_ast::{Expr, Stmt};
 use ruff_text_size::{Ranged, TextRange};
 
-use crate::{constants::SymType, core::{file_mgr::{FileInfo, FileMgr}, symbols::symbol::Symbol}, features::ast_utils::AstUtils, features::xml_ast_utils::{XmlAstResult, XmlAstUtils}, threads::SessionInfo, utils::PathSanitizer};
+use crate::{constants::SymType, core::{entry_point::EntryPointType, file_mgr::{FileInfo, FileMgr}, odoo::SyncOdoo, symbols::symbol::Symbol}, features::ast_utils::AstUtils, features::xml_ast_utils::{XmlAstResult, XmlAstUtils}, oyarn, threads::SessionInfo, utils::PathSanitizer, S};
 
 
 pub struct ReferenceFeature {
 
 }
 
+/// Whether a [`NameMatch`] came from an actual Python identifier (a `Name` or an attribute's
+/// `attr`) or from a string literal that happens to spell the same name (e.g. a model name in
+/// `_name = "res.partner"` or the comodel name in `fields.Many2one("res.partner")`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NameMatchKind {
+    Identifier,
+    StringLiteral,
+}
+
+struct NameMatch {
+    range: TextRange,
+    kind: NameMatchKind,
+}
+
 struct NameFinderVisitor<'a> {
     target_name: &'a str,
-    matches: Vec<TextRange>,
+    /// The dotted model name a string literal must spell exactly to be recorded as a
+    /// [`NameMatchKind::StringLiteral`] match. `None` when the target symbol isn't a model (or
+    /// one of its fields), in which case no string literal can possibly be a reference to it.
+    literal_target: Option<&'a str>,
+    matches: Vec<NameMatch>,
 }
 
 impl<'a> NameFinderVisitor<'a> {
-    fn new(target_name: &'a str) -> Self {
+    fn new(target_name: &'a str, literal_target: Option<&'a str>) -> Self {
         Self {
             target_name,
+            literal_target,
             matches: Vec::new(),
         }
     }
 
-    fn find_all_names(stmts: &[Stmt], target_name: &str) -> Vec<TextRange> {
-        let mut visitor = NameFinderVisitor::new(target_name);
+    fn find_all_names(stmts: &[Stmt], target_name: &str, literal_target: Option<&str>) -> Vec<NameMatch> {
+        let mut visitor = NameFinderVisitor::new(target_name, literal_target);
         for stmt in stmts {
             visitor.visit_stmt(stmt);
         }
@@ -36,10 +55,17 @@ impl<'a> NameFinderVisitor<'a> {
 
 impl<'a> Visitor<'a> for NameFinderVisitor<'a> {
     fn visit_expr(&mut self, expr: &'a Expr) {
-        if let Expr::Name(name_expr) = expr {
-            if name_expr.id.as_str() == self.target_name {
-                self.matches.push(name_expr.range());
+        match expr {
+            Expr::Name(name_expr) if name_expr.id.as_str() == self.target_name => {
+                self.matches.push(NameMatch { range: name_expr.range(), kind: NameMatchKind::Identifier });
+            }
+            Expr::Attribute(attr_expr) if attr_expr.attr.as_str() == self.target_name => {
+                self.matches.push(NameMatch { range: attr_expr.attr.range(), kind: NameMatchKind::Identifier });
             }
+            Expr::StringLiteral(str_expr) if self.literal_target.is_some_and(|model_name| str_expr.value.to_string() == model_name) => {
+                self.matches.push(NameMatch { range: str_expr.range(), kind: NameMatchKind::StringLiteral });
+            }
+            _ => {}
         }
         walk_expr(self, expr);
     }
@@ -55,7 +81,7 @@ impl ReferenceFeature {
 
         let file_info_ast = file_info.borrow().file_info_ast.clone();
         let file_info_ast_borrow = file_info_ast.borrow();
-        
+
         let (analyse_ast_result, _range, _expr, _call_expr) = AstUtils::get_symbols(session, &file_info_ast_borrow, file_symbol, offset as u32);
 
         if analyse_ast_result.evaluations.is_empty() {
@@ -65,45 +91,135 @@ impl ReferenceFeature {
         let eval = &analyse_ast_result.evaluations[0];
         let target_symbol = eval.symbol.get_symbol_as_weak(session, &mut None, &mut vec![], None);
         let target_symbol_rc = target_symbol.weak.upgrade()?;
+        drop(file_info_ast_borrow);
 
         let symbol_name = target_symbol_rc.borrow().name().to_string();
 
-        let stmts = file_info_ast_borrow.get_stmts()?;
-
-        let name_matches = NameFinderVisitor::find_all_names(stmts, &symbol_name);
+        // Collect every file of the workspace that could contain a reference, starting with
+        // the current one since it is already parsed.
+        let mut candidate_files: Vec<Rc<RefCell<Symbol>>> = vec![file_symbol.clone()];
+        for entry in session.sync_odoo.entry_point_mgr.clone().borrow().iter_all() {
+            if entry.borrow().typ == EntryPointType::BUILTIN {
+                continue;
+            }
+            ReferenceFeature::collect_file_symbols(&entry.borrow().root, &mut candidate_files);
+        }
 
-        if name_matches.is_empty() {
-            return None;
+        // If the target is an Odoo model (or one of its fields), also follow the model's
+        // `_inherit`/`_inherits` chain so references spread across multiple classes are found.
+        if let Some(model_name) = ReferenceFeature::model_name_for_symbol(&target_symbol_rc) {
+            if let Some(model) = session.sync_odoo.models.get(&model_name).cloned() {
+                let from_module = file_symbol.borrow().find_module();
+                for class_symbol in model.borrow().get_symbols(session, from_module) {
+                    if let Some(class_file) = class_symbol.borrow().get_file().and_then(|w| w.upgrade()) {
+                        if !candidate_files.iter().any(|f| Rc::ptr_eq(f, &class_file)) {
+                            candidate_files.push(class_file);
+                        }
+                    }
+                }
+            }
         }
 
-        let file_path = file_symbol.borrow().paths()[0].clone();
         let mut locations = Vec::new();
+        let mut seen_files: Vec<*const RefCell<Symbol>> = Vec::new();
+        for candidate_file in candidate_files {
+            let ptr = Rc::as_ptr(&candidate_file);
+            if seen_files.contains(&ptr) {
+                continue;
+            }
+            seen_files.push(ptr);
+            ReferenceFeature::find_references_in_file(session, &candidate_file, &symbol_name, &target_symbol_rc, &mut locations);
+        }
+
+        if locations.is_empty() {
+            None
+        } else {
+            Some(locations)
+        }
+    }
+
+    /// If `symbol` is an Odoo model class or one of its fields, return the model's name so the
+    /// caller can also search the rest of the `_inherit`/`_inherits` chain.
+    fn model_name_for_symbol(symbol: &Rc<RefCell<Symbol>>) -> Option<crate::constants::OYarn> {
+        let sym = symbol.borrow();
+        if sym.typ() == SymType::CLASS {
+            return sym.as_class_sym()._model.as_ref().map(|m| m.name.clone());
+        }
+        let parent = sym.parent()?.upgrade()?;
+        let parent_ref = parent.borrow();
+        if parent_ref.typ() == SymType::CLASS {
+            return parent_ref.as_class_sym()._model.as_ref().map(|m| m.name.clone());
+        }
+        None
+    }
+
+    /// Recursively walk the symbol tree rooted at `symbol`, collecting every `FileSymbol`.
+    fn collect_file_symbols(symbol: &Rc<RefCell<Symbol>>, out: &mut Vec<Rc<RefCell<Symbol>>>) {
+        if symbol.borrow().typ() == SymType::FILE {
+            out.push(symbol.clone());
+        }
+        for child in symbol.borrow().all_symbols() {
+            ReferenceFeature::collect_file_symbols(&child, out);
+        }
+    }
+
+    /// Find every occurrence of `symbol_name` in `candidate_file` that resolves back to
+    /// `target_symbol_rc`, appending the corresponding [`Location`]s to `locations`.
+    fn find_references_in_file(session: &mut SessionInfo, candidate_file: &Rc<RefCell<Symbol>>, symbol_name: &str, target_symbol_rc: &Rc<RefCell<Symbol>>, locations: &mut Vec<Location>) {
+        let Some(file_path) = candidate_file.borrow().paths().first().cloned() else {
+            return;
+        };
+        let Some(file_info) = session.sync_odoo.get_file_mgr().borrow().get_file_info(&file_path) else {
+            return;
+        };
+        if file_info.borrow().file_info_ast.borrow().text_document.is_none() {
+            file_info.borrow_mut().prepare_ast(session);
+        }
+        let file_info_ast = file_info.borrow().file_info_ast.clone();
+        let file_info_ast_borrow = file_info_ast.borrow();
+        let Some(stmts) = file_info_ast_borrow.get_stmts() else {
+            return;
+        };
 
-        for match_range in name_matches {
-            let match_offset = match_range.start().to_u32();
-            let scope = Symbol::get_scope_symbol(file_symbol.clone(), match_offset, false);
-            AstUtils::build_scope(session, &scope);
+        // A string literal is only searched for, and only ever accepted as a reference, when it
+        // spells the dotted model name the target symbol resolves to (e.g. the `"res.partner"` in
+        // `_inherit = "res.partner"` or `fields.Many2one("res.partner")`): unlike identifiers,
+        // literals carry no scope to verify against, so matching the model's own identifying
+        // string - not the bare Python symbol name, which a dotted model name never equals - is
+        // the only confirmation available to us.
+        //
+        // Note: this repo's cache-side `CachedModelData` tracks richer relational metadata
+        // (`computes`, `inherits`, ...), but it lives purely in the on-disk persistence format and
+        // isn't reachable from a live `Symbol` here, so we can't additionally cross-check against
+        // it; the model-name match above is the full extent of what's confirmable at this layer.
+        let target_model_name = ReferenceFeature::model_name_for_symbol(target_symbol_rc).map(|n| n.to_string());
+        let name_matches = NameFinderVisitor::find_all_names(stmts, symbol_name, target_model_name.as_deref());
+        drop(file_info_ast_borrow);
 
-            let inferred = Symbol::infer_name(&mut session.sync_odoo, &scope, &symbol_name, Some(match_offset));
+        for name_match in name_matches {
+            let refers_to_same_symbol = match name_match.kind {
+                // The visitor only ever records a StringLiteral match when the literal's text
+                // already equals `target_model_name`, so there's nothing left to re-check here.
+                NameMatchKind::StringLiteral => true,
+                NameMatchKind::Identifier => {
+                    let match_offset = name_match.range.start().to_u32();
+                    let scope = Symbol::get_scope_symbol(candidate_file.clone(), match_offset, false);
+                    AstUtils::build_scope(session, &scope);
 
-            let refers_to_same_symbol = inferred.symbols.iter().any(|sym| {
-                Rc::ptr_eq(sym, &target_symbol_rc)
-            });
+                    let inferred = Symbol::infer_name(&mut session.sync_odoo, &scope, symbol_name, Some(match_offset));
+
+                    inferred.symbols.iter().any(|sym| Rc::ptr_eq(sym, target_symbol_rc))
+                }
+            };
 
             if refers_to_same_symbol {
-                let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &file_path, &match_range);
+                let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &file_path, &name_match.range);
                 locations.push(Location {
                     uri: FileMgr::pathname2uri(&file_path),
                     range,
                 });
             }
         }
-
-        if locations.is_empty() {
-            None
-        } else {
-            Some(locations)
-        }
     }
 
     pub fn get_references_xml(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, line: u32, character: u32) -> Option<Vec<Location>> {
@@ -154,7 +270,96 @@ impl ReferenceFeature {
         None
     }
 
-    pub fn get_references_csv(_session: &mut SessionInfo, _file_symbol: &Rc<RefCell<Symbol>>, _file_info: &Rc<RefCell<FileInfo>>, _line: u32, _character: u32) -> Option<Vec<Location>> {
-        None
+    pub fn get_references_csv(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, line: u32, character: u32) -> Option<Vec<Location>> {
+        let offset = file_info.borrow().position_to_offset(line, character, session.sync_odoo.encoding);
+        let content = file_info.borrow().file_info_ast.borrow().text_document.as_ref()?.contents().to_string();
+
+        let file_path = file_symbol.borrow().paths().first().cloned()?;
+        let model_name = PathBuf::from(&file_path).file_stem()?.to_str()?.to_string();
+        let model = session.sync_odoo.models.get(&oyarn!("{}", model_name)).cloned()?;
+
+        let (row_index, line_start, data_line) = ReferenceFeature::csv_line_at_offset(&content, offset);
+        let fields = ReferenceFeature::split_csv_line(data_line);
+        let rel_offset = offset.saturating_sub(line_start);
+        let column_index = fields.iter().position(|(start, end)| rel_offset >= *start && rel_offset <= *end)?;
+
+        let header_line = content.lines().next()?;
+        let header_fields = ReferenceFeature::split_csv_line(header_line);
+        let (h_start, h_end) = *header_fields.get(column_index)?;
+        let column = header_line[h_start..h_end].trim();
+        let field_name = column.strip_suffix(":id").or_else(|| column.strip_suffix("/id")).unwrap_or(column);
+
+        let mut locations = Vec::new();
+        if row_index == 0 {
+            // Cursor is on the header row: resolve to the field symbol(s) on the model.
+            // The "id" column is metadata (it names the row's own external id), not a field.
+            if field_name != "id" {
+                let from_module = file_symbol.borrow().find_module();
+                for class_symbol in model.borrow().get_symbols(session, from_module.clone()) {
+                    let (members, _) = class_symbol.borrow().get_member_symbol(session, &S!(field_name), from_module.clone(), false, false, true, true, false);
+                    for member in members {
+                        let Some(member_file) = member.borrow().get_file().and_then(|w| w.upgrade()) else { continue };
+                        let Some(member_path) = member_file.borrow().paths().first().cloned() else { continue };
+                        let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &member_path, &member.borrow().range());
+                        locations.push(Location { uri: FileMgr::pathname2uri(&member_path), range });
+                    }
+                }
+            }
+        } else if field_name == "id" || column.ends_with(":id") || column.ends_with("/id") {
+            // Cursor is on an external-id cell: resolve other references to the same xml id,
+            // including matching <record id="..."> elements in XML data files.
+            let (v_start, v_end) = fields[column_index];
+            let value = data_line[v_start..v_end].trim();
+            if !value.is_empty() {
+                let match_range = std::ops::Range { start: line_start + v_start, end: line_start + v_end };
+                let xml_ids = SyncOdoo::get_xml_ids(session, file_symbol, value, &match_range, &mut vec![]);
+                for xml_id in xml_ids {
+                    let Some(xml_file) = xml_id.get_file_symbol().and_then(|w| w.upgrade()) else { continue };
+                    let Some(path) = xml_file.borrow().paths().first().cloned() else { continue };
+                    let range = session.sync_odoo.get_file_mgr().borrow().std_range_to_range(session, &path, &xml_id.get_range());
+                    locations.push(Location { uri: FileMgr::pathname2uri(&path), range });
+                }
+            }
+        }
+
+        if locations.is_empty() {
+            None
+        } else {
+            Some(locations)
+        }
+    }
+
+    /// Split a single CSV line (without its trailing newline) into `(start, end)` byte ranges for
+    /// each field, honouring double-quoted fields that may themselves contain a comma.
+    fn split_csv_line(line: &str) -> Vec<(usize, usize)> {
+        let mut fields = Vec::new();
+        let mut start = 0;
+        let mut in_quotes = false;
+        for (i, ch) in line.char_indices() {
+            match ch {
+                '"' => in_quotes = !in_quotes,
+                ',' if !in_quotes => {
+                    fields.push((start, i));
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        fields.push((start, line.len()));
+        fields
+    }
+
+    /// Return `(row_index, line_start_offset, line_content)` for the line containing `offset`,
+    /// where `row_index` 0 is the header row.
+    fn csv_line_at_offset(content: &str, offset: usize) -> (usize, usize, &str) {
+        let mut start = 0;
+        for (row_index, raw_line) in content.split('\n').enumerate() {
+            let end = start + raw_line.len();
+            if offset <= end {
+                return (row_index, start, raw_line.trim_end_matches('\r'));
+            }
+            start = end + 1;
+        }
+        (0, 0, "")
     }
 }
@@ -0,0 +1,57 @@
+use std::{cell::RefCell, collections::HashMap, rc::Rc};
+
+use lsp_types::{TextEdit, WorkspaceEdit};
+
+use crate::{core::file_mgr::FileInfo, core::symbols::symbol::Symbol, features::ast_utils::AstUtils, features::references::ReferenceFeature, threads::SessionInfo};
+
+pub struct RenameFeature;
+
+impl RenameFeature {
+    /// Renames every reference to the symbol under the cursor, or returns an `Err` explaining
+    /// why it couldn't: distinguishing "no symbol under cursor", "symbol is external", and "no
+    /// references found" lets callers surface a reason to the user instead of a silent no-op.
+    pub fn rename(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, line: u32, character: u32, new_name: &str) -> Result<WorkspaceEdit, String> {
+        RenameFeature::ensure_renamable(session, file_symbol, file_info, line, character)?;
+
+        let locations = ReferenceFeature::get_references(session, file_symbol, file_info, line, character)
+            .ok_or_else(|| "no references found for the symbol under the cursor".to_string())?;
+
+        let mut changes: HashMap<lsp_types::Uri, Vec<TextEdit>> = HashMap::new();
+        for location in locations {
+            changes.entry(location.uri).or_default().push(TextEdit {
+                range: location.range,
+                new_text: new_name.to_string(),
+            });
+        }
+
+        Ok(WorkspaceEdit {
+            changes: Some(changes),
+            ..Default::default()
+        })
+    }
+
+    /// Renaming is refused for symbols defined outside the workspace (stubs, dependencies, the
+    /// Odoo core itself): we have no write access to them, and a partial rename of only the
+    /// workspace-side references would silently break the symbol everywhere else.
+    fn ensure_renamable(session: &mut SessionInfo, file_symbol: &Rc<RefCell<Symbol>>, file_info: &Rc<RefCell<FileInfo>>, line: u32, character: u32) -> Result<(), String> {
+        let offset = file_info.borrow().position_to_offset(line, character, session.sync_odoo.encoding);
+        let file_info_ast = file_info.borrow().file_info_ast.clone();
+        let file_info_ast_borrow = file_info_ast.borrow();
+        let (analyse_ast_result, _range, _expr, _call_expr) = AstUtils::get_symbols(session, &file_info_ast_borrow, file_symbol, offset as u32);
+        drop(file_info_ast_borrow);
+
+        let Some(eval) = analyse_ast_result.evaluations.first() else {
+            return Err("no symbol found under the cursor".to_string());
+        };
+        let target_symbol = eval.symbol.get_symbol_as_weak(session, &mut None, &mut vec![], None);
+        let Some(target_symbol_rc) = target_symbol.weak.upgrade() else {
+            return Err("no symbol found under the cursor".to_string());
+        };
+
+        if target_symbol_rc.borrow().is_external() {
+            return Err("refusing to rename a symbol defined outside the workspace".to_string());
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+
+use lsp_types::{Diagnostic, DiagnosticSeverity, NumberOrString};
+use ruff_python_ast::visitor::{walk_expr, Visitor};
+use ruff_python_ast::{Expr, Stmt};
+use ruff_text_size::{Ranged, TextRange};
+
+use crate::core::file_mgr::FileInfoAst;
+use crate::threads::SessionInfo;
+
+struct ImportBinding {
+    name: String,
+    range: TextRange,
+}
+
+pub struct UnusedImportsFeature;
+
+impl UnusedImportsFeature {
+    /// Flags module-level imports whose bound local name is never read anywhere else in the
+    /// file and is not re-exported through `__all__`, mirroring `rustc_resolve`'s `check_unused`.
+    /// `import a.b.c` binds `a`, so the whole dotted chain collapses to its first segment unless
+    /// an `as` alias is present.
+    pub fn check(session: &mut SessionInfo, path: &str, file_info_ast: &FileInfoAst) -> Vec<Diagnostic> {
+        let Some(stmts) = file_info_ast.get_stmts() else { return vec![] };
+
+        let mut bindings = Vec::new();
+        let mut exported = HashSet::new();
+        for stmt in stmts.iter() {
+            UnusedImportsFeature::collect_import_bindings(stmt, &mut bindings);
+            UnusedImportsFeature::collect_dunder_all(stmt, &mut exported);
+        }
+        if bindings.is_empty() {
+            return vec![];
+        }
+
+        let mut reads = HashSet::new();
+        let mut reader = NameReadVisitor { reads: &mut reads };
+        for stmt in stmts.iter() {
+            reader.visit_stmt(stmt);
+        }
+
+        bindings.into_iter()
+            .filter(|binding| !reads.contains(&binding.name) && !exported.contains(&binding.name))
+            .map(|binding| {
+                let range = session.sync_odoo.get_file_mgr().borrow().text_range_to_range(session, &path.to_string(), &binding.range);
+                Diagnostic {
+                    range,
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    code: Some(NumberOrString::String("OLS02002".to_string())),
+                    message: format!("Unused import `{}`", binding.name),
+                    ..Default::default()
+                }
+            }).collect()
+    }
+
+    fn collect_import_bindings(stmt: &Stmt, bindings: &mut Vec<ImportBinding>) {
+        match stmt {
+            Stmt::Import(import_stmt) => {
+                for alias in import_stmt.names.iter() {
+                    let name = match &alias.asname {
+                        Some(asname) => asname.id.to_string(),
+                        None => alias.name.id.as_str().split('.').next().unwrap_or(alias.name.id.as_str()).to_string(),
+                    };
+                    bindings.push(ImportBinding { name, range: alias.range() });
+                }
+            }
+            Stmt::ImportFrom(import_from) => {
+                for alias in import_from.names.iter() {
+                    if alias.name.id.as_str() == "*" {
+                        continue;
+                    }
+                    let name = match &alias.asname {
+                        Some(asname) => asname.id.to_string(),
+                        None => alias.name.id.to_string(),
+                    };
+                    bindings.push(ImportBinding { name, range: alias.range() });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn collect_dunder_all(stmt: &Stmt, exported: &mut HashSet<String>) {
+        let Stmt::Assign(assign) = stmt else { return };
+        let is_dunder_all = assign.targets.iter().any(|t| matches!(t, Expr::Name(n) if n.id.as_str() == "__all__"));
+        if !is_dunder_all {
+            return;
+        }
+        let elements: &[Expr] = match assign.value.as_ref() {
+            Expr::List(list) => &list.elts,
+            Expr::Tuple(tuple) => &tuple.elts,
+            _ => return,
+        };
+        for elt in elements {
+            if let Expr::StringLiteral(s) = elt {
+                exported.insert(s.value.to_string());
+            }
+        }
+    }
+}
+
+struct NameReadVisitor<'a> {
+    reads: &'a mut HashSet<String>,
+}
+
+impl<'a, 'b> Visitor<'b> for NameReadVisitor<'a> {
+    fn visit_expr(&mut self, expr: &'b Expr) {
+        if let Expr::Name(name) = expr {
+            self.reads.insert(name.id.to_string());
+        }
+        walk_expr(self, expr);
+    }
+}
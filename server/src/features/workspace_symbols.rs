@@ -1,18 +1,19 @@
-use std::{cell::{Ref, RefCell}, rc::Rc};
+use std::{cell::RefCell, rc::Rc};
 
 use lsp_server::{ErrorCode, ResponseError};
 use lsp_types::{Location, WorkspaceLocation, WorkspaceSymbol, WorkspaceSymbolResponse};
 use ruff_text_size::{TextRange, TextSize};
 
-use crate::{S, constants::{PackageType, SymType}, core::{entry_point::EntryPointType, file_mgr::FileMgr, symbols::symbol::Symbol}, threads::SessionInfo, utils::string_fuzzy_contains};
+use crate::{S, constants::{PackageType, SymType}, core::{entry_point::EntryPointType, file_mgr::FileMgr, symbol_index::{self, SymbolLocator}, symbol_sql_cache::{self, Cached, CachedSymbolRow, FileSymbolCacheKey}, symbols::symbol::Symbol}, threads::SessionInfo};
 
 pub struct WorkspaceSymbolFeature;
 
 impl WorkspaceSymbolFeature {
 
+    /// Answers `workspace/symbol` from the FST-backed [`symbol_index`] instead of re-walking the
+    /// whole symbol tree on every keystroke: each non-builtin entry point's modules are indexed
+    /// once (lazily, on first use or after an eviction) and then just queried.
     pub fn get_workspace_symbols(session: &mut SessionInfo<'_>, query: String) -> Result<Option<WorkspaceSymbolResponse>, ResponseError> {
-        let mut symbols = vec![];
-        let ep_mgr = session.sync_odoo.entry_point_mgr.clone();
         let mut can_resolve_location_range = false;
         if let Some(cap_workspace) = session.sync_odoo.capabilities.workspace.as_ref() {
             if let Some(workspace_symb) = cap_workspace.symbol.as_ref() {
@@ -26,11 +27,13 @@ impl WorkspaceSymbolFeature {
                 }
             }
         }
+
+        let ep_mgr = session.sync_odoo.entry_point_mgr.clone();
         for entry in ep_mgr.borrow().iter_all() {
             if entry.borrow().typ == EntryPointType::BUILTIN || entry.borrow().typ == EntryPointType::PUBLIC { //We don't want to search in builtins
                 continue;
             }
-            if WorkspaceSymbolFeature::browse_symbol(session, &entry.borrow().root, &query, None, None, can_resolve_location_range, &mut symbols) {
+            if WorkspaceSymbolFeature::ensure_indexed(session, &entry.borrow().root) {
                 return Err(ResponseError {
                     code: ErrorCode::RequestCanceled as i32,
                     message: S!("Workspace Symbol request cancelled"),
@@ -38,45 +41,133 @@ impl WorkspaceSymbolFeature {
                 });
             }
         }
+
+        let symbols = symbol_index::search_all_modules(&query)
+            .into_iter()
+            .filter_map(|locator| WorkspaceSymbolFeature::to_workspace_symbol(session, locator, can_resolve_location_range))
+            .collect();
         Ok(Some(WorkspaceSymbolResponse::Nested(symbols)))
     }
 
-    /**
-     * Return true if the request has been cancelled and the cancellation should be propagated
-     */
-    fn browse_symbol(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>, query: &String, parent: Option<String>, parent_path: Option<&String>, can_resolve_location_range: bool, results: &mut Vec<WorkspaceSymbol>) -> bool {
+    /// Indexes every `PackageType::MODULE` package found under `symbol` that isn't already
+    /// cached, one [`symbol_index::ModuleSymbolIndex`] per module keyed by that module's own
+    /// sanitized path - so a later file change only needs to evict and rebuild its one segment.
+    /// Anything that never finds a module ancestor (e.g. an entry point with no addon modules
+    /// directly under its root) is bucketed under `symbol`'s own path instead.
+    ///
+    /// Return true if the request has been cancelled and the cancellation should be propagated.
+    fn ensure_indexed(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>) -> bool {
         let symbol_borrowed = symbol.borrow();
-        if symbol_borrowed.typ() == SymType::VARIABLE {
-            return false;
-        }
-        if symbol_borrowed.typ() == SymType::FILE { //to avoid too many locks
+        let own_key = symbol_borrowed.paths().first().cloned().unwrap_or_else(|| symbol_borrowed.name().to_string());
+
+        if symbol_borrowed.typ() == SymType::PACKAGE(PackageType::MODULE) {
+            if symbol_index::has_module_index(&own_key) {
+                return false;
+            }
             if session.sync_odoo.is_request_cancelled() {
                 return true;
             }
+            drop(symbol_borrowed);
+            let mut entries = Vec::new();
+            WorkspaceSymbolFeature::collect_entries(session, symbol, None, None, &mut entries);
+            symbol_index::rebuild_module_index(&own_key, entries);
+            return false;
+        }
+
+        let children: Vec<_> = symbol_borrowed.all_symbols().collect();
+        drop(symbol_borrowed);
+        let mut found_any_module = false;
+        for child in &children {
+            if child.borrow().typ() == SymType::PACKAGE(PackageType::MODULE) {
+                found_any_module = true;
+                if WorkspaceSymbolFeature::ensure_indexed(session, child) {
+                    return true;
+                }
+            }
         }
-        let container_name = match &parent {
-            Some(p) => Some(p.clone()),
-            None => None,
-        };
+        if found_any_module || symbol_index::has_module_index(&own_key) {
+            return false;
+        }
+        if session.sync_odoo.is_request_cancelled() {
+            return true;
+        }
+        let mut entries = Vec::new();
+        WorkspaceSymbolFeature::collect_entries(session, symbol, None, None, &mut entries);
+        symbol_index::rebuild_module_index(&own_key, entries);
+        false
+    }
+
+    /// Walks `symbol`'s subtree collecting `(key, locator)` pairs for every indexable name, the
+    /// same way [`WorkspaceSymbolFeature::collect_entries_raw`] does - except at a `SymType::FILE`
+    /// node, where the whole subtree's rows are served from (and persisted to) the on-disk
+    /// `symbol_sql_cache` instead of always being recomputed: a file whose content hash hasn't
+    /// changed since the last session is served without re-walking its AST-derived symbols at all.
+    fn collect_entries(session: &mut SessionInfo, symbol: &Rc<RefCell<Symbol>>, parent: Option<String>, parent_path: Option<&String>, out: &mut Vec<(String, SymbolLocator)>) {
+        let is_file = symbol.borrow().typ() == SymType::FILE;
+        if is_file {
+            let path = symbol.borrow().paths().first().cloned();
+            if let Some(path) = path {
+                let content_hash = session.sync_odoo.get_file_mgr().borrow().get_file_info(&path)
+                    .map(|file_info| file_info.borrow().file_info_ast.borrow().text_hash);
+                if let Some(content_hash) = content_hash {
+                    let rows = symbol_sql_cache::with_cache(|con| {
+                        FileSymbolCacheKey { path: path.clone() }.cached(con, content_hash, || {
+                            let mut sub_entries = Vec::new();
+                            WorkspaceSymbolFeature::collect_entries_raw(symbol, parent.clone(), Some(&path), &mut sub_entries);
+                            sub_entries.iter().map(|(_, locator)| locator_to_row(locator)).collect()
+                        })
+                    });
+                    if let Some(rows) = rows {
+                        out.extend(rows.into_iter().map(|row| {
+                            let locator = row_to_locator(row);
+                            (locator.name.clone(), locator)
+                        }));
+                        return;
+                    }
+                }
+            }
+        }
+        WorkspaceSymbolFeature::collect_entries_raw(symbol, parent, parent_path, out);
+    }
+
+    /// The actual symbol-tree walk, collecting `(key, locator)` pairs for every indexable name:
+    /// the symbol's own name, its quoted model name if it's a model class, and - for
+    /// `PackageType::MODULE` packages - its `xmlid.`-prefixed external ids. Mirrors the filtering
+    /// this feature used to do inline (skip `SymType::VARIABLE`, require a resolvable path).
+    fn collect_entries_raw(symbol: &Rc<RefCell<Symbol>>, parent: Option<String>, parent_path: Option<&String>, out: &mut Vec<(String, SymbolLocator)>) {
+        let symbol_borrowed = symbol.borrow();
+        if symbol_borrowed.typ() == SymType::VARIABLE {
+            return;
+        }
+        let container_name = parent.clone();
         let path = symbol_borrowed.paths();
         let path = if path.len() == 1 {
             Some(&path[0])
-        } else if path.len() == 0{
+        } else if path.is_empty() {
             parent_path
         } else {
             None
         };
-        if path.is_some() && symbol_borrowed.has_range() {
-            //Test if symbol should be returned
-            if string_fuzzy_contains(&symbol_borrowed.name(), &query) {
-                WorkspaceSymbolFeature::add_symbol_to_results(session, &symbol_borrowed, &symbol_borrowed.name().to_string(), path.unwrap(), container_name.clone(), Some(symbol_borrowed.range()), can_resolve_location_range, results);
-            }
-            //Test if symbol is a model
-            if symbol_borrowed.typ() == SymType::CLASS && symbol_borrowed.as_class_sym()._model.is_some() {
-                let model_data = symbol_borrowed.as_class_sym()._model.as_ref().unwrap();
-                let model_name = S!("\"") + &model_data.name.to_string() + "\"";
-                if string_fuzzy_contains(&model_name, &query) {
-                    WorkspaceSymbolFeature::add_symbol_to_results(session, &symbol_borrowed, &model_name, path.unwrap(), container_name.clone(), Some(symbol_borrowed.range()), can_resolve_location_range, results);
+        if let Some(path) = path {
+            if symbol_borrowed.has_range() {
+                let name = symbol_borrowed.name().to_string();
+                out.push((name.clone(), SymbolLocator {
+                    name,
+                    kind: symbol_borrowed.get_lsp_symbol_kind(),
+                    container_name: container_name.clone(),
+                    path: path.clone(),
+                    range: Some(symbol_borrowed.range()),
+                }));
+                if symbol_borrowed.typ() == SymType::CLASS && symbol_borrowed.as_class_sym()._model.is_some() {
+                    let model_data = symbol_borrowed.as_class_sym()._model.as_ref().unwrap();
+                    let model_name = S!("\"") + &model_data.name.to_string() + "\"";
+                    out.push((model_name.clone(), SymbolLocator {
+                        name: model_name,
+                        kind: symbol_borrowed.get_lsp_symbol_kind(),
+                        container_name,
+                        path: path.clone(),
+                        range: Some(symbol_borrowed.range()),
+                    }));
                 }
             }
         }
@@ -84,64 +175,59 @@ impl WorkspaceSymbolFeature {
             let module = symbol_borrowed.as_module_package();
             for xml_id_name in module.xml_id_locations.keys() {
                 let xml_name = S!("xmlid.") + xml_id_name;
-                if string_fuzzy_contains(&xml_name, &query) {
-                    let xml_data = module.get_xml_id(xml_id_name);
-                    for data in xml_data {
-                        let xml_file_symbol = data.get_xml_file_symbol();
-                        if let Some(xml_file_symbol) = xml_file_symbol {
-                            if let Some(path) = xml_file_symbol.borrow().paths().get(0) {
-                                let range = data.get_range();
-                                let text_range = TextRange::new(TextSize::new(range.start as u32), TextSize::new(range.end as u32));
-                                WorkspaceSymbolFeature::add_symbol_to_results(session, &xml_file_symbol.borrow(), &xml_name, path, Some(symbol_borrowed.name().to_string()), Some(&text_range), can_resolve_location_range, results);
-                            }
+                for data in module.get_xml_id(xml_id_name) {
+                    let xml_file_symbol = data.get_xml_file_symbol();
+                    if let Some(xml_file_symbol) = xml_file_symbol {
+                        if let Some(xml_path) = xml_file_symbol.borrow().paths().get(0) {
+                            let range = data.get_range();
+                            let text_range = TextRange::new(TextSize::new(range.start as u32), TextSize::new(range.end as u32));
+                            out.push((xml_name.clone(), SymbolLocator {
+                                name: xml_name.clone(),
+                                kind: xml_file_symbol.borrow().get_lsp_symbol_kind(),
+                                container_name: Some(symbol_borrowed.name().to_string()),
+                                path: xml_path.clone(),
+                                range: Some(text_range),
+                            }));
                         }
                     }
                 }
             }
         }
         for sym in symbol_borrowed.all_symbols() {
-            if WorkspaceSymbolFeature::browse_symbol(session, &sym, query, Some(symbol_borrowed.name().to_string()), path, can_resolve_location_range, results) {
-                return true;
-            }
+            WorkspaceSymbolFeature::collect_entries_raw(&sym, Some(symbol_borrowed.name().to_string()), path, out);
         }
-        false
     }
 
-    fn add_symbol_to_results(session: &mut SessionInfo, symbol: &Ref<Symbol>, name: &String, path: &String, container_name: Option<String>, range: Option<&TextRange>, can_resolve_location_range: bool, results: &mut Vec<WorkspaceSymbol>) {
+    fn to_workspace_symbol(session: &mut SessionInfo, locator: SymbolLocator, can_resolve_location_range: bool) -> Option<WorkspaceSymbol> {
         let location = if can_resolve_location_range {
             lsp_types::OneOf::Right(WorkspaceLocation {
-                uri: FileMgr::pathname2uri(path)
+                uri: FileMgr::pathname2uri(&locator.path)
             })
         } else {
-            let file_info = session.sync_odoo.get_file_mgr().borrow().get_file_info(path);
-            let Some(range) = range else {
-                return;
-            };
-            if let Some(file_info) = file_info {
-                lsp_types::OneOf::Left(Location::new(
-                    FileMgr::pathname2uri(path),
-                    file_info.borrow().text_range_to_range(range, session.sync_odoo.encoding)
-                ))
-            } else {
-                return;
-            }
+            let file_info = session.sync_odoo.get_file_mgr().borrow().get_file_info(&locator.path);
+            let range = locator.range.as_ref()?;
+            let file_info = file_info?;
+            lsp_types::OneOf::Left(Location::new(
+                FileMgr::pathname2uri(&locator.path),
+                file_info.borrow().text_range_to_range(range, session.sync_odoo.encoding)
+            ))
         };
-        let data = if can_resolve_location_range && range.is_some() {
-            Some(lsp_types::LSPAny::Array(vec![
-                lsp_types::LSPAny::Number(serde_json::Number::from(range.as_ref().unwrap().start().to_u32())),
-                lsp_types::LSPAny::Number(serde_json::Number::from(range.as_ref().unwrap().end().to_u32())),
+        let data = if can_resolve_location_range {
+            locator.range.map(|range| lsp_types::LSPAny::Array(vec![
+                lsp_types::LSPAny::Number(serde_json::Number::from(range.start().to_u32())),
+                lsp_types::LSPAny::Number(serde_json::Number::from(range.end().to_u32())),
             ]))
         } else {
             None
         };
-        results.push(WorkspaceSymbol {
-            name: name.clone(),
-            kind: symbol.get_lsp_symbol_kind(),
+        Some(WorkspaceSymbol {
+            name: locator.name,
+            kind: locator.kind,
             tags: None,
-            container_name,
-            location: location,
-            data: data,
-        });
+            container_name: locator.container_name,
+            location,
+            data,
+        })
     }
 
     pub fn resolve_workspace_symbol(session: &mut SessionInfo<'_>, symbol: &WorkspaceSymbol) -> Result<WorkspaceSymbol, ResponseError> {
@@ -191,4 +277,37 @@ impl WorkspaceSymbolFeature {
         }
     }
 
+}
+
+/// Flattens a [`SymbolLocator`] into the plain-column shape `symbol_sql_cache` stores. `kind` is
+/// round-tripped through `serde_json` since `lsp_types::SymbolKind` doesn't expose its inner
+/// `i32` directly, and a missing `range` (never actually produced by `collect_entries_raw` for a
+/// pushed locator, but kept defensive) falls back to an empty `0..0` range rather than panicking.
+fn locator_to_row(locator: &SymbolLocator) -> CachedSymbolRow {
+    let kind = serde_json::to_value(locator.kind).ok()
+        .and_then(|v| v.as_i64())
+        .unwrap_or(0) as i32;
+    let (range_start, range_end) = locator.range
+        .map(|r| (r.start().to_u32(), r.end().to_u32()))
+        .unwrap_or((0, 0));
+    CachedSymbolRow {
+        name: locator.name.clone(),
+        kind,
+        container: locator.container_name.clone(),
+        path: locator.path.clone(),
+        range_start,
+        range_end,
+    }
+}
+
+/// Inverse of [`locator_to_row`].
+fn row_to_locator(row: CachedSymbolRow) -> SymbolLocator {
+    let kind = serde_json::from_value(serde_json::Value::from(row.kind)).unwrap_or(lsp_types::SymbolKind::VARIABLE);
+    SymbolLocator {
+        name: row.name,
+        kind,
+        container_name: row.container,
+        path: row.path,
+        range: Some(TextRange::new(TextSize::new(row.range_start), TextSize::new(row.range_end))),
+    }
 }
\ No newline at end of file
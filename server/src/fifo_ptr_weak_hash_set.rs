@@ -1,10 +1,15 @@
 use std::{collections::VecDeque, hash::RandomState, rc::{Rc, Weak}};
 use weak_table::{PtrWeakHashSet};
 
+/// A [`PtrWeakHashSet`] that also remembers insertion order, so callers that need a bounded
+/// working set (the symbol cache, in particular) can evict the oldest entry instead of an
+/// arbitrary one. With no `max_capacity` set this behaves like a plain insertion-ordered weak
+/// set; [`Self::with_capacity`] turns it into a FIFO cache that evicts on overflow.
 #[derive(Debug)]
 pub struct FifoPtrWeakHashSet<T> {
     set: PtrWeakHashSet<Weak<T>, RandomState>,
     queue: VecDeque<Weak<T>>,
+    max_capacity: Option<usize>,
 }
 
 impl<T> FifoPtrWeakHashSet<T>
@@ -13,15 +18,41 @@ impl<T> FifoPtrWeakHashSet<T>
         Self {
             set: PtrWeakHashSet::new(),
             queue: VecDeque::new(),
+            max_capacity: None,
         }
     }
 
-    pub fn insert(&mut self, v: Rc<T>) {
-        if !self.set.insert(v.clone()) { //it returns true if absent (wrong doc)
-            self.queue.push_back(Rc::downgrade(&v));
+    /// Same as [`Self::new`], but [`Self::insert`] will evict the oldest live entry whenever
+    /// inserting a new one would make `len()` exceed `max`.
+    pub fn with_capacity(max: usize) -> Self {
+        Self {
+            set: PtrWeakHashSet::new(),
+            queue: VecDeque::new(),
+            max_capacity: Some(max),
         }
     }
 
+    /// Inserts `v` at the back of the FIFO queue. If a capacity was set via
+    /// [`Self::with_capacity`] and inserting `v` would push `len()` past it, the oldest live
+    /// entry is evicted first and returned - the caller is expected to call something like
+    /// `evict_data()` on it before dropping it.
+    ///
+    /// Returns `None` when nothing was evicted (including when `v` was already present, which
+    /// doesn't grow the set).
+    pub fn insert(&mut self, v: Rc<T>) -> Option<Rc<T>> {
+        let is_new = self.set.insert(v.clone());
+        if !is_new {
+            return None;
+        }
+        self.queue.push_back(Rc::downgrade(&v));
+
+        let evicted = match self.max_capacity {
+            Some(max) if self.set.len() > max => self.pop_oldest(),
+            _ => None,
+        };
+        evicted
+    }
+
     pub fn iter(&self) -> impl Iterator<Item = Rc<T>> {
         self.queue.iter().filter_map(|weak| weak.upgrade())
     }
@@ -47,6 +78,23 @@ impl<T> FifoPtrWeakHashSet<T>
         false
     }
 
+    /// Pops and removes the oldest entry still alive, for manual pressure-driven reclamation
+    /// (a caller noticing memory pressure outside of a normal `insert`) as well as for
+    /// [`Self::insert`]'s own over-capacity eviction. Dead weak refs at the front of the queue -
+    /// left behind once their last `Rc` was dropped elsewhere - are discarded as the scan passes
+    /// over them, so the queue stays compacted instead of accumulating stale entries.
+    pub fn pop_oldest(&mut self) -> Option<Rc<T>> {
+        while let Some(weak) = self.queue.pop_front() {
+            if let Some(strong) = weak.upgrade() {
+                self.set.remove(&strong);
+                return Some(strong);
+            }
+            // Dead entry: already gone from `set` (PtrWeakHashSet reaps dead entries lazily),
+            // just drop it from the queue and keep scanning.
+        }
+        None
+    }
+
     pub fn is_empty(&self) -> bool {
         self.set.is_empty()
     }
@@ -4,13 +4,13 @@ use crossbeam_channel::{Receiver, Sender, TryRecvError};
 use lsp_server::{Message, RequestId, Response, ResponseError};
 use lsp_types::{notification::{DidChangeConfiguration, DidChangeTextDocument, DidChangeWatchedFiles, DidChangeWorkspaceFolders,
     DidCloseTextDocument, DidCreateFiles, DidDeleteFiles, DidOpenTextDocument, DidRenameFiles, DidSaveTextDocument, LogMessage,
-    Notification, ShowMessage}, request::{Completion, DocumentSymbolRequest, GotoDefinition, GotoTypeDefinitionResponse, HoverRequest, References, Request, Shutdown}, CompletionResponse, DocumentSymbolResponse, Hover, Location, LogMessageParams, MessageType, ShowMessageParams};
+    Notification, ShowMessage}, request::{Completion, DocumentSymbolRequest, GotoDefinition, GotoTypeDefinition, GotoTypeDefinitionResponse, HoverRequest, References, Request, Shutdown, WillRenameFiles}, CompletionResponse, DocumentSymbolResponse, Hover, Location, LogMessageParams, MessageType, ShowMessageParams, WorkspaceEdit};
 use serde::{de::DeserializeOwned, Serialize};
 use serde_json::Value;
 use tracing::{error, info, warn};
 use crate::{constants::MAX_WATCHED_FILES_UPDATES_BEFORE_RESTART, create_session};
 
-use crate::{core::{file_mgr::NoqaInfo, odoo::{Odoo, SyncOdoo}}, server::ServerError, utils::PathSanitizer, S};
+use crate::{core::{file_info_cache::FileInfoCacheManager, file_mgr::NoqaInfo, file_system::FileSystem, odoo::{Odoo, SyncOdoo}}, server::ServerError, utils::PathSanitizer, S};
 
 pub struct SessionInfo<'a> {
     sender: Sender<Message>,
@@ -19,6 +19,14 @@ pub struct SessionInfo<'a> {
     delayed_process_sender: Option<Sender<DelayedProcessingMessage>>,
     pub noqas_stack: Vec<NoqaInfo>,
     pub current_noqa: NoqaInfo,
+    /// Where on-the-fly file loads (a path not yet in `FileMgr`'s cache) actually read from.
+    /// Cloned from `SyncOdoo` per-session rather than stored directly so a remote/WSL provider or
+    /// a test fixture can be swapped in without touching every call site.
+    pub file_system: Arc<dyn FileSystem>,
+    /// The persistent, cross-session diagnostics cache, if its per-user cache directory (under
+    /// the platform's local data directory) could be created. `None` (rather than failing file analysis) when it couldn't, e.g. a
+    /// read-only home directory.
+    pub file_info_cache: Option<Arc<FileInfoCacheManager>>,
 }
 
 impl <'a> SessionInfo<'a> {
@@ -329,6 +337,11 @@ pub fn message_processor_thread_main(sync_odoo: Arc<Mutex<SyncOdoo>>, generic_re
                             SyncOdoo::process_rebuilds(&mut session, true);
                             to_value::<GotoTypeDefinitionResponse>(Odoo::handle_goto_definition(&mut session, serde_json::from_value(r.params).unwrap()))
                         },
+                        GotoTypeDefinition::METHOD => {
+                            let mut session = create_session!(sender, receiver, sync_odoo, delayed_process_sender);
+                            SyncOdoo::process_rebuilds(&mut session, true);
+                            to_value::<GotoTypeDefinitionResponse>(Odoo::handle_goto_type_definition(&mut session, serde_json::from_value(r.params).unwrap()))
+                        },
                         References::METHOD => {
                             let mut session = create_session!(sender, receiver, sync_odoo, delayed_process_sender);
                             SyncOdoo::process_rebuilds(&mut session, true);
@@ -343,6 +356,10 @@ pub fn message_processor_thread_main(sync_odoo: Arc<Mutex<SyncOdoo>>, generic_re
                             SyncOdoo::process_rebuilds(&mut session, true);
                             to_value::<CompletionResponse>(Odoo::handle_autocomplete(&mut session, serde_json::from_value(r.params).unwrap()))
                         },
+                        WillRenameFiles::METHOD => {
+                            let mut session = create_session!(sender, receiver, sync_odoo, delayed_process_sender);
+                            to_value::<WorkspaceEdit>(Odoo::handle_will_rename_files(&mut session, serde_json::from_value(r.params).unwrap()))
+                        },
                         _ => {error!("Request not handled by main thread: {}", r.method); (None, Some(ResponseError{
                             code: 1,
                             message: S!("Request not handled by the server"),
@@ -406,8 +423,18 @@ pub fn message_processor_thread_main(sync_odoo: Arc<Mutex<SyncOdoo>>, generic_re
                         "custom/server/init" => {
                             let mut session = create_session!(sender, receiver, sync_odoo, delayed_process_sender);
                             Odoo::init(&mut session);
+                            if session.sync_odoo.config.eval_cache_enabled {
+                                SyncOdoo::load_eval_cache(&mut session);
+                            }
                         }
-                        Shutdown::METHOD => { warn!("Main thread - got shutdown."); return;} // should be already caught
+                        Shutdown::METHOD => {
+                            warn!("Main thread - got shutdown.");
+                            let mut session = create_session!(sender, receiver, sync_odoo, delayed_process_sender);
+                            if session.sync_odoo.config.eval_cache_enabled {
+                                SyncOdoo::save_eval_cache(&mut session);
+                            }
+                            return;
+                        } // should be already caught
                         _ => {error!("Notification not handled by main thread: {}", n.method)}
                     }
                 },
@@ -423,6 +450,8 @@ pub fn message_processor_thread_main(sync_odoo: Arc<Mutex<SyncOdoo>>, generic_re
 #[macro_export]
 macro_rules! create_session {
     ($sender:expr, $receiver:expr, $sync_odoo:expr, $delayed_sender:expr) => {{
+        let file_system = $sync_odoo.lock().unwrap().file_system.clone();
+        let file_info_cache = $sync_odoo.lock().unwrap().file_info_cache.clone();
         SessionInfo {
             sender: $sender.clone(),
             receiver: $receiver.clone(),
@@ -430,6 +459,8 @@ macro_rules! create_session {
             delayed_process_sender: Some($delayed_sender.clone()),
             noqas_stack: vec![],
             current_noqa: NoqaInfo::None,
+            file_system,
+            file_info_cache,
         }
     }};
 }
\ No newline at end of file
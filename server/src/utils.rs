@@ -43,26 +43,65 @@ pub fn get_python_command() -> Option<String> {
     None
 }
 
+/// Caches, per parent directory, the exact on-disk casing of every entry name, so repeated
+/// `is_file_cs`/`is_dir_cs` checks that share an ancestor (e.g. while indexing a whole addon
+/// tree) don't each re-`read_dir` it. Only meaningful on Windows, where the filesystem is
+/// case-insensitive but Odoo's own module/path conventions are case-sensitive. Entries are
+/// evicted by [`invalidate_case_cache`] when a watcher/`didChange` event reports that a
+/// directory's contents may have changed.
+#[cfg(target_os = "windows")]
+fn case_sensitive_cache() -> &'static std::sync::Mutex<HashMap<String, std::collections::HashSet<String>>> {
+    static CACHE: LazyLock<std::sync::Mutex<HashMap<String, std::collections::HashSet<String>>>> =
+        LazyLock::new(|| std::sync::Mutex::new(HashMap::new()));
+    &CACHE
+}
+
+/// Returns whether `name` exists directly under `parent` with that exact casing, reading
+/// `parent`'s entries from disk only on the first query for that directory.
+#[cfg(target_os = "windows")]
+fn case_exists_exact(parent: &Path, name: &std::ffi::OsStr) -> bool {
+    let key = parent.to_path_buf().sanitize();
+    let mut cache = case_sensitive_cache().lock().unwrap();
+    let entries = cache.entry(key).or_insert_with(|| {
+        let mut names = std::collections::HashSet::new();
+        if let Ok(read_dir) = fs::read_dir(parent) {
+            for entry in read_dir.flatten() {
+                if let Some(n) = entry.file_name().to_str() {
+                    names.insert(n.to_string());
+                }
+            }
+        }
+        names
+    });
+    name.to_str().is_some_and(|n| entries.contains(n))
+}
+
+/// Evicts the cached directory-entry listing for `path`'s own parent, e.g. when a
+/// `didChange`/watcher event reports that `path` was created, deleted or renamed. A no-op on
+/// platforms where `is_file_cs`/`is_dir_cs` don't need case-exact resolution.
+pub fn invalidate_case_cache(path: &str) {
+    #[cfg(target_os = "windows")]
+    {
+        if let Some(parent) = Path::new(path).parent() {
+            let key = parent.to_path_buf().sanitize();
+            case_sensitive_cache().lock().unwrap().remove(&key);
+        }
+    }
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = path;
+    }
+}
+
 #[cfg(target_os = "windows")]
 pub fn is_file_cs(path: String) -> bool {
     let mut p = Path::new(&path);
     if p.exists() && p.is_file() {
-        while p.parent().is_some() {
-            let mut found = false;
-            if let Ok(entries) = fs::read_dir(p.parent().unwrap()) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        if entry.file_name() == p.components().last().unwrap().as_os_str() {
-                            found = true;
-                            break;
-                        }
-                    }
-                }
-            }
-            if !found {
+        while let Some(parent) = p.parent() {
+            if !case_exists_exact(parent, p.components().last().unwrap().as_os_str()) {
                 return false;
             }
-            p = p.parent().unwrap();
+            p = parent;
         }
         return true;
     }
@@ -79,22 +118,11 @@ pub fn is_file_cs(path: String) -> bool {
 pub fn is_dir_cs(path: String) -> bool {
     let mut p = Path::new(&path);
     if p.exists() && p.is_dir() {
-        while p.parent().is_some() {
-            let mut found = false;
-            if let Ok(entries) = fs::read_dir(p.parent().unwrap()) {
-                for entry in entries {
-                    if let Ok(entry) = entry {
-                        if entry.file_name() == p.components().last().unwrap().as_os_str() {
-                            found = true;
-                            break;
-                        }
-                    }
-                }
-            }
-            if !found {
+        while let Some(parent) = p.parent() {
+            if !case_exists_exact(parent, p.components().last().unwrap().as_os_str()) {
                 return false;
             }
-            p = p.parent().unwrap();
+            p = parent;
         }
         return true;
     }
@@ -284,21 +312,94 @@ pub fn has_template(template: &str) -> bool {
     TEMPLATE_REGEX.is_match(template)
 }
 
+/// Fills every `${...}` placeholder in `template`, resolving each one against `vars` (plain
+/// `${key}`), the process environment (`${env:VAR}`), or a literal fallback used when the key is
+/// missing or empty (`${key:-fallback}`). The fallback itself is filled recursively, so
+/// `${ODOO_PATH:-${workspaceFolder}/odoo}` works. Unlike a simple regex replace, placeholders are
+/// matched with brace counting so a fallback can safely contain another `${...}`. Returns `Err`
+/// when a key has no value and no fallback was given - the same contract callers already
+/// rely on for `${workspaceFolder}`/`${userHome}` - or when a `${` is never closed.
 pub fn fill_template(template: &str, vars: &HashMap<String, String>) -> Result<String, String> {
-    let mut invalid = None;
-
-    let result = TEMPLATE_REGEX.replace_all(template, |captures: &regex::Captures| -> String {
-        let key = captures[1].to_string();
-        if let Some(value) = vars.get(&key) {
-            value.clone()
+    let bytes = template.as_bytes();
+    let mut result = String::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'$' && i + 1 < bytes.len() && bytes[i + 1] == b'{' {
+            let inner_start = i + 2;
+            let mut depth = 1usize;
+            let mut j = inner_start;
+            while j < bytes.len() && depth > 0 {
+                if bytes[j] == b'$' && j + 1 < bytes.len() && bytes[j + 1] == b'{' {
+                    depth += 1;
+                    j += 2;
+                    continue;
+                }
+                if bytes[j] == b'}' {
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                    j += 1;
+                    continue;
+                }
+                j += 1;
+            }
+            if depth != 0 {
+                return Err(format!("Unterminated template variable in pattern: {}", template));
+            }
+            result.push_str(&resolve_template_key(&template[inner_start..j], vars)?);
+            i = j + 1;
         } else {
-            invalid = Some(format!("Invalid key ({}) in pattern", key));
-            S!("")
+            let char_len = template[i..].chars().next().map(|c| c.len_utf8()).unwrap_or(1);
+            result.push_str(&template[i..i + char_len]);
+            i += char_len;
         }
-    });
-    match invalid {
-        Some(err) => Err(err),
-        None => Ok(S!(result)),
+    }
+    Ok(result)
+}
+
+/// Resolves the content of a single `${...}` placeholder: `raw` is everything between the
+/// braces, e.g. `workspaceFolder`, `env:ODOO_PATH` or `ODOO_PATH:-${workspaceFolder}/odoo`.
+fn resolve_template_key(raw: &str, vars: &HashMap<String, String>) -> Result<String, String> {
+    let raw_bytes = raw.as_bytes();
+    let mut depth = 0i32;
+    let mut split_at = None;
+    let mut k = 0;
+    while k < raw_bytes.len() {
+        if raw_bytes[k] == b'$' && k + 1 < raw_bytes.len() && raw_bytes[k + 1] == b'{' {
+            depth += 1;
+            k += 2;
+            continue;
+        }
+        if raw_bytes[k] == b'}' {
+            depth -= 1;
+            k += 1;
+            continue;
+        }
+        if depth == 0 && raw_bytes[k] == b':' && k + 1 < raw_bytes.len() && raw_bytes[k + 1] == b'-' {
+            split_at = Some(k);
+            break;
+        }
+        k += 1;
+    }
+
+    let (key, fallback) = match split_at {
+        Some(idx) => (&raw[..idx], Some(&raw[idx + 2..])),
+        None => (raw, None),
+    };
+
+    let value = if let Some(var_name) = key.strip_prefix("env:") {
+        std::env::var(var_name).ok()
+    } else {
+        vars.get(key).cloned()
+    };
+
+    match fallback {
+        Some(fallback) => match value {
+            Some(v) if !v.is_empty() => Ok(v),
+            _ => fill_template(fallback, vars),
+        },
+        None => value.ok_or_else(|| format!("Invalid key ({}) in pattern", key)),
     }
 }
 
@@ -320,7 +421,9 @@ pub fn build_pattern_map(ws_folders: &HashMap<String, String>) -> HashMap<String
 /// Fill the template with the given pattern map.
 /// While also checking it with the predicate function.
 /// pass `|_| true` to skip the predicate check.
-/// Currently, only the workspaceFolder[:workspace_name] and userHome variables are supported.
+/// In addition to workspaceFolder[:workspace_name] and userHome, `${env:VAR}` and
+/// `${key:-fallback}` are resolved through the same `fill_template` call, so env lookups and
+/// defaulted keys are validated by `predicate` exactly like any other path.
 pub fn fill_validate_path<F, P>(
     ws_folders: &HashMap<String, String>,
     workspace_name: Option<&String>,
@@ -406,6 +509,64 @@ pub fn string_fuzzy_contains(string: &str, pattern: &str) -> bool {
     false
 }
 
+/// Scored variant of [`string_fuzzy_contains`]: returns `None` when `pattern` is not a
+/// subsequence of `string` (same case-insensitive rule), otherwise `Some(score)` computed during
+/// the same left-to-right scan, higher is a better match. Lets callers (completion, workspace
+/// symbols) sort candidates instead of only filtering them.
+///
+/// The score rewards matches that land on a "word boundary" (start of the string, right after a
+/// `_`/`.`, or a lowercase-to-uppercase transition like the `B` in `fooBar`), rewards runs of
+/// consecutive matched characters, and penalizes the gap of unmatched characters skipped to reach
+/// each match - so `res.pa` ranks `res.partner` above a match that only hits late, scattered
+/// characters.
+pub fn fuzzy_match_score(string: &str, pattern: &str) -> Option<i32> {
+    const BOUNDARY_BONUS: i32 = 10;
+    const CONSECUTIVE_BONUS: i32 = 5;
+    const GAP_PENALTY: i32 = 1;
+
+    if pattern.is_empty() {
+        return Some(0);
+    }
+
+    let chars: Vec<char> = string.chars().collect();
+    let mut pattern_chars = pattern.chars().map(|c| c.to_ascii_lowercase());
+    let mut pattern_char = pattern_chars.next();
+
+    let mut score = 0i32;
+    let mut previous_matched = false;
+    let mut last_match_index: Option<usize> = None;
+
+    for (i, &char) in chars.iter().enumerate() {
+        let Some(target) = pattern_char else { break };
+        if char.to_ascii_lowercase() != target {
+            previous_matched = false;
+            continue;
+        }
+
+        let is_boundary = i == 0
+            || matches!(chars[i - 1], '_' | '.')
+            || (chars[i - 1].is_lowercase() && char.is_uppercase());
+        if is_boundary {
+            score += BOUNDARY_BONUS;
+        }
+        if previous_matched {
+            score += CONSECUTIVE_BONUS;
+        }
+        if let Some(last) = last_match_index {
+            score -= GAP_PENALTY * (i - last - 1) as i32;
+        }
+
+        last_match_index = Some(i);
+        previous_matched = true;
+        pattern_char = pattern_chars.next();
+    }
+
+    if pattern_char.is_some() {
+        return None;
+    }
+    Some(score)
+}
+
 #[macro_export]
 macro_rules! warn_or_panic {
     ($($arg:tt)*) => {
@@ -0,0 +1,32 @@
+use std::env;
+
+use lsp_types::{DiagnosticSeverity, NumberOrString};
+use odoo_ls_server::{
+    core::file_mgr::DiagnosticSeverityOverride,
+    S,
+    utils::PathSanitizer,
+};
+
+use crate::setup::setup::*;
+
+/// Same fixture as [`super::ols01000::test_ols01000`], but with a workspace-level severity
+/// override downgrading `OLS01000` to a warning - proving the per-code config added to
+/// `DiagnosticsConfig` actually changes what gets published, not just the bare code string.
+#[test]
+fn test_ols01000_severity_override() {
+    let (mut odoo, mut config) = setup_server(false);
+    config.diagnostics_config.overrides.insert(S!("OLS01000"), DiagnosticSeverityOverride::Warning);
+    let mut session = create_init_session(&mut odoo, config);
+    let path = env::current_dir().unwrap().join("tests/data/python/diagnostics/ols01000.py").sanitize();
+    prepare_custom_entry_point(&mut session, &path);
+    let diagnostics = get_diagnostics_for_path(&mut session, &path);
+    assert_eq!(diagnostics.len(), 1);
+    let diag = &diagnostics[0];
+    let code = match &diag.code {
+        Some(NumberOrString::String(code)) => code,
+        Some(NumberOrString::Number(num)) => panic!("Unexpected numeric code: {}", num),
+        None => panic!("Diagnostic code is None"),
+    };
+    assert!(code == &S!("OLS01000"));
+    assert!(diag.severity.is_some_and(|s| s == DiagnosticSeverity::WARNING));
+}
@@ -11,7 +11,7 @@ use lsp_types::notification::{Notification, PublishDiagnostics};
 use odoo_ls_server::S;
 use odoo_ls_server::core::file_mgr::FileMgr;
 use odoo_ls_server::utils::get_python_command;
-use odoo_ls_server::{core::{config::{ConfigEntry, DiagMissingImportsMode}, entry_point::EntryPointMgr, odoo::SyncOdoo}, threads::SessionInfo, utils::PathSanitizer as _};
+use odoo_ls_server::{core::{config::{ConfigEntry, DiagMissingImportsMode}, diagnostic_batch::{DiagnosticBatchParams, DIAGNOSTIC_BATCH_METHOD}, entry_point::EntryPointMgr, odoo::SyncOdoo}, threads::SessionInfo, utils::PathSanitizer as _};
 
 use tracing::{info, level_filters::LevelFilter};
 use tracing_appender::rolling::RollingFileAppender;
@@ -77,7 +77,9 @@ pub fn prepare_custom_entry_point(session: &mut SessionInfo, path: &str){
         range_length: None,
             text: text}]);
     EntryPointMgr::create_new_custom_entry_for_path(session, &ep_path, &ep_path);
-    let (file_updated, file_info) = session.sync_odoo.get_file_mgr().borrow_mut().update_file_info(session, path, content.as_ref(), Some(1), false);
+    let (file_updated, file_info) = session.sync_odoo.get_file_mgr().borrow_mut()
+        .update_file_info(session, path, content.as_ref(), Some(1), false)
+        .expect("a freshly created custom entry point is always tracked/of interest");
     SyncOdoo::process_rebuilds(session, false);
 }
 
@@ -119,6 +121,33 @@ pub fn get_diagnostics_for_paths(session: &mut SessionInfo, paths: &Vec<String>)
     return res;
 }
 
+/// Like [`get_diagnostics_for_paths`], but keeps draining messages until a `$Odoo/diagnosticBatch`
+/// notification with a `batch_id` at least as high as `batch_id` arrives, so callers don't have
+/// to guess how many `PublishDiagnostics` messages a rebuild will emit before asserting.
+pub fn get_diagnostics_after_batch(session: &mut SessionInfo, batch_id: u64, paths: &Vec<String>) -> HashMap<String, Vec<Diagnostic>> {
+    let mut res = HashMap::new();
+    while let Some(msg) = session._consume_message() {
+        match msg {
+            Message::Notification(n) => {
+                if n.method == PublishDiagnostics::METHOD {
+                    let params: PublishDiagnosticsParams = serde_json::from_value(n.params).expect("Unable to parse PublishDiagnosticsParams");
+                    let params_path = FileMgr::uri2pathname(params.uri.as_str());
+                    if paths.contains(&params_path) {
+                        res.entry(params_path).or_insert_with(Vec::new).extend(params.diagnostics);
+                    }
+                } else if n.method == DIAGNOSTIC_BATCH_METHOD {
+                    let params: DiagnosticBatchParams = serde_json::from_value(n.params).expect("Unable to parse DiagnosticBatchParams");
+                    if params.batch_id >= batch_id {
+                        break;
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+    return res;
+}
+
 pub fn get_diagnostics_test_comments(session: &mut SessionInfo, path: &str) -> Vec<(u32, Vec<String>)> {
     let file_mgr = session.sync_odoo.get_file_mgr();
     let file_mgr = file_mgr.borrow();
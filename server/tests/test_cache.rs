@@ -60,11 +60,15 @@ fn test_cached_function_restoration() {
                 name: "self".to_string(),
                 arg_type: "ARG".to_string(),
                 has_default: false,
+                annotation: None,
+                default_type: None,
             },
             CachedArgument {
                 name: "param1".to_string(),
                 arg_type: "ARG".to_string(),
                 has_default: true,
+                annotation: Some("int".to_string()),
+                default_type: Some("int".to_string()),
             },
         ],
         symbols: vec![
@@ -194,26 +198,36 @@ fn test_argument_type_conversion() {
         name: "a".to_string(),
         arg_type: "POS_ONLY".to_string(),
         has_default: false,
+        annotation: None,
+        default_type: None,
     };
     let arg_arg = CachedArgument {
         name: "b".to_string(),
         arg_type: "ARG".to_string(),
         has_default: true,
+        annotation: None,
+        default_type: None,
     };
     let arg_vararg = CachedArgument {
         name: "c".to_string(),
         arg_type: "VARARG".to_string(),
         has_default: false,
+        annotation: None,
+        default_type: None,
     };
     let arg_kwonly = CachedArgument {
         name: "d".to_string(),
         arg_type: "KWORD_ONLY".to_string(),
         has_default: false,
+        annotation: None,
+        default_type: None,
     };
     let arg_kwarg = CachedArgument {
         name: "e".to_string(),
         arg_type: "KWARG".to_string(),
         has_default: false,
+        annotation: None,
+        default_type: None,
     };
     
     use odoo_ls_server::core::symbols::function_symbol::ArgumentType;
@@ -224,3 +238,44 @@ fn test_argument_type_conversion() {
     assert!(matches!(arg_kwonly.to_argument_type(), ArgumentType::KWORD_ONLY));
     assert!(matches!(arg_kwarg.to_argument_type(), ArgumentType::KWARG));
 }
+
+/// Round-trips a [`CachedModule`] through the rkyv-backed cache file format: written with
+/// [`save_module_rkyv`], read back zero-copy with [`load_module_rkyv`], and checked field-by-field
+/// against the original to prove the mmap'd archive actually reflects what was serialized (not
+/// just that the header validates).
+#[cfg(feature = "rkyv-cache")]
+#[test]
+fn test_rkyv_cache_roundtrip() {
+    use odoo_ls_server::core::cache::CachedModule;
+    use odoo_ls_server::core::rkyv_cache::{load_module_rkyv, save_module_rkyv};
+
+    let module = CachedModule {
+        name: "my_module".to_string(),
+        path: "/addons/my_module".to_string(),
+        dir_name: "my_module".to_string(),
+        module_name: "my_module".to_string(),
+        depends: vec!["base".to_string()],
+        all_depends: vec!["base".to_string(), "web".to_string()],
+        data: vec!["views/my_view.xml".to_string()],
+        file_hashes: std::collections::HashMap::from([("models/my_model.py".to_string(), 42u64)]),
+        models: vec![],
+        xml_ids: std::collections::HashMap::new(),
+        is_external: false,
+        processed_text_hash: 1234,
+        files: vec![],
+    };
+
+    let path = std::env::temp_dir().join("odoo_ls_test_rkyv_cache_roundtrip.rkyv");
+    assert!(save_module_rkyv(&path, &module), "saving the rkyv module cache should succeed");
+
+    let handle = load_module_rkyv(&path).expect("a freshly saved rkyv cache should load back");
+    let archived = handle.get();
+    assert_eq!(archived.name.as_str(), "my_module");
+    assert_eq!(archived.depends.len(), 1);
+    assert_eq!(archived.depends[0].as_str(), "base");
+    assert_eq!(archived.all_depends.len(), 2);
+    assert_eq!(archived.processed_text_hash, 1234);
+    assert_eq!(archived.file_hashes.len(), 1);
+
+    std::fs::remove_file(&path).ok();
+}
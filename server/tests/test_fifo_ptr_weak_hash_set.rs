@@ -0,0 +1,60 @@
+use std::rc::Rc;
+
+use odoo_ls_server::fifo_ptr_weak_hash_set::FifoPtrWeakHashSet;
+
+#[test]
+fn test_insert_reports_queue_order() {
+    let mut set: FifoPtrWeakHashSet<i32> = FifoPtrWeakHashSet::new();
+    let a = Rc::new(1);
+    let b = Rc::new(2);
+    let c = Rc::new(3);
+
+    set.insert(a.clone());
+    set.insert(b.clone());
+    set.insert(c.clone());
+
+    // iter() walks the FIFO queue in insertion order - this would come back empty (the bug this
+    // test guards against) if insert() only pushed to the queue for values already present.
+    let order: Vec<i32> = set.iter().map(|rc| *rc).collect();
+    assert_eq!(order, vec![1, 2, 3]);
+    assert_eq!(set.len(), 3);
+}
+
+#[test]
+fn test_capacity_bounded_insert_evicts_oldest() {
+    let mut set: FifoPtrWeakHashSet<i32> = FifoPtrWeakHashSet::with_capacity(2);
+    let a = Rc::new(1);
+    let b = Rc::new(2);
+    let c = Rc::new(3);
+
+    assert!(set.insert(a.clone()).is_none());
+    assert!(set.insert(b.clone()).is_none());
+
+    // Inserting a third entry over capacity should evict the oldest (`a`), in FIFO order.
+    let evicted = set.insert(c.clone()).expect("inserting past capacity should evict the oldest entry");
+    assert_eq!(*evicted, 1);
+
+    assert_eq!(set.len(), 2);
+    assert!(!set.contains(&a));
+    assert!(set.contains(&b));
+    assert!(set.contains(&c));
+}
+
+#[test]
+fn test_pop_oldest_skips_dead_weak_refs() {
+    let mut set: FifoPtrWeakHashSet<i32> = FifoPtrWeakHashSet::new();
+    let a = Rc::new(1);
+    let b = Rc::new(2);
+
+    set.insert(a.clone());
+    set.insert(b.clone());
+
+    // Drop the only strong reference to `a` without going through `remove` - its weak entry at
+    // the front of the queue is now dead, and pop_oldest should skip over it to the next live one.
+    drop(a);
+
+    let popped = set.pop_oldest().expect("pop_oldest should find the next live entry");
+    assert_eq!(*popped, 2);
+    // The queue itself is now fully drained - nothing left to pop, dead or alive.
+    assert!(set.pop_oldest().is_none());
+}
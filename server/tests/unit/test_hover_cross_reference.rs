@@ -0,0 +1,36 @@
+use std::env;
+use std::path::PathBuf;
+
+use lsp_types::HoverContents;
+use odoo_ls_server::core::odoo::SyncOdoo;
+use odoo_ls_server::features::hover::HoverFeature;
+use odoo_ls_server::utils::PathSanitizer;
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+fn test_hover_resolves_docstring_cross_reference_to_another_module() {
+    let (mut odoo, config) = setup::setup::setup_server(true);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let path = env::current_dir()
+        .unwrap()
+        .join("tests/data/addons/module_1/models/base_test_models.py")
+        .sanitize();
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_info = file_mgr.borrow().get_file_info(&path).unwrap();
+    let file_symbol = SyncOdoo::get_symbol_of_opened_file(&mut session, &PathBuf::from(&path))
+        .expect("failed to get file symbol");
+
+    // Hover over a function whose docstring carries a `:class:`/`:meth:`-style cross-reference
+    // to a symbol defined in another file of the same addon; the resulting markdown link proves
+    // the reference resolved through a real scope lookup rather than a same-file sibling scan.
+    let hover = HoverFeature::get_hover(&mut session, &file_symbol, &file_info, 33, 10)
+        .expect("hover over a documented symbol should produce content");
+
+    let HoverContents::Markup(markup) = hover.contents else {
+        panic!("expected markdown hover contents");
+    };
+    assert!(markup.value.contains("]("), "a resolved cross-reference should render as a markdown link, got: {}", markup.value);
+}
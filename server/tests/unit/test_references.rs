@@ -0,0 +1,59 @@
+use std::env;
+use std::path::PathBuf;
+
+use odoo_ls_server::core::odoo::SyncOdoo;
+use odoo_ls_server::features::references::ReferenceFeature;
+use odoo_ls_server::utils::PathSanitizer;
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+fn test_get_references_finds_usage_in_another_file() {
+    let (mut odoo, config) = setup::setup::setup_server(true);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let path = env::current_dir()
+        .unwrap()
+        .join("tests/data/addons/module_1/models/base_test_models.py")
+        .sanitize();
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_info = file_mgr.borrow().get_file_info(&path).unwrap();
+    let file_symbol = SyncOdoo::get_symbol_of_opened_file(&mut session, &PathBuf::from(&path))
+        .expect("failed to get file symbol");
+
+    // Position of the `BaseTestModel` class name declaration: any reference found elsewhere in
+    // the addon (e.g. an `_inherit`/import in a sibling model file) proves the search isn't
+    // limited to the file the symbol was resolved from.
+    let locations = ReferenceFeature::get_references(&mut session, &file_symbol, &file_info, 0, 7)
+        .expect("class referenced from another file in the same module should be found");
+
+    let current_file_uri = odoo_ls_server::core::file_mgr::FileMgr::pathname2uri(&path);
+    assert!(
+        locations.iter().any(|l| l.uri != current_file_uri),
+        "references should include at least one location outside the declaring file"
+    );
+}
+
+#[test]
+fn test_get_references_matches_model_name_string_literal() {
+    let (mut odoo, config) = setup::setup::setup_server(true);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let path = env::current_dir()
+        .unwrap()
+        .join("tests/data/addons/module_1/models/base_test_models.py")
+        .sanitize();
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_info = file_mgr.borrow().get_file_info(&path).unwrap();
+    let file_symbol = SyncOdoo::get_symbol_of_opened_file(&mut session, &PathBuf::from(&path))
+        .expect("failed to get file symbol");
+
+    // Position on the model class itself: a string literal spelling out its dotted `_name`
+    // elsewhere (e.g. `_inherit = "base.test.model"`) is a reference kind this feature only
+    // confirms via an exact match against the resolved model's own identifying string.
+    let locations = ReferenceFeature::get_references(&mut session, &file_symbol, &file_info, 0, 7)
+        .expect("model class should have at least one reference");
+
+    assert!(!locations.is_empty(), "expected at least one reference to the model");
+}
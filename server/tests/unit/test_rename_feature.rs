@@ -0,0 +1,55 @@
+use std::env;
+use std::path::PathBuf;
+
+use odoo_ls_server::core::odoo::SyncOdoo;
+use odoo_ls_server::features::rename::RenameFeature;
+use odoo_ls_server::utils::PathSanitizer;
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+fn test_rename_local_variable_updates_every_reference() {
+    let (mut odoo, config) = setup::setup::setup_server(false);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let path = env::current_dir()
+        .unwrap()
+        .join("tests/data/python/expressions/follow_ref.py")
+        .sanitize();
+    setup::setup::prepare_custom_entry_point(&mut session, &path);
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_info = file_mgr.borrow().get_file_info(&path).unwrap();
+    let file_symbol = SyncOdoo::get_symbol_of_opened_file(&mut session, &PathBuf::from(&path))
+        .expect("failed to get file symbol");
+
+    let edit = RenameFeature::rename(&mut session, &file_symbol, &file_info, 3, 0, "renamed_a")
+        .expect("renaming a workspace-local variable should produce a WorkspaceEdit");
+
+    let changes = edit.changes.expect("rename should produce text-document changes");
+    let edits: Vec<_> = changes.values().flatten().collect();
+    assert!(!edits.is_empty(), "rename should touch at least one location");
+    assert!(edits.iter().all(|e| e.new_text == "renamed_a"));
+}
+
+#[test]
+fn test_rename_external_symbol_is_refused() {
+    let (mut odoo, config) = setup::setup::setup_server(false);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let path = env::current_dir()
+        .unwrap()
+        .join("tests/data/python/expressions/follow_ref.py")
+        .sanitize();
+    setup::setup::prepare_custom_entry_point(&mut session, &path);
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_info = file_mgr.borrow().get_file_info(&path).unwrap();
+    let file_symbol = SyncOdoo::get_symbol_of_opened_file(&mut session, &PathBuf::from(&path))
+        .expect("failed to get file symbol");
+
+    // `int` at (6, 4) resolves to the builtin type, which is defined outside the workspace -
+    // renaming it must be refused rather than silently producing a partial, broken edit.
+    let err = RenameFeature::rename(&mut session, &file_symbol, &file_info, 6, 4, "renamed_int")
+        .expect_err("renaming a symbol defined outside the workspace should be refused");
+    assert!(err.contains("external") || err.contains("outside the workspace"), "error should explain the refusal, got: {}", err);
+}
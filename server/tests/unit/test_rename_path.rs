@@ -0,0 +1,30 @@
+use std::env;
+
+use odoo_ls_server::core::file_mgr::FileMgr;
+use odoo_ls_server::utils::PathSanitizer;
+
+#[path = "../setup/mod.rs"]
+mod setup;
+
+#[test]
+fn test_rename_path_keeps_file_info_and_moves_key() {
+    let (mut odoo, config) = setup::setup::setup_server(false);
+    let mut session = setup::setup::create_init_session(&mut odoo, config);
+    let old_path = env::current_dir().unwrap().join("tests/data/python/expressions/assign.py").sanitize();
+    setup::setup::prepare_custom_entry_point(&mut session, &old_path);
+
+    let old_file_info = session.sync_odoo.get_file_mgr().borrow().files.get(&old_path).cloned()
+        .expect("entry point file should be tracked under its original path");
+
+    let new_path = env::current_dir().unwrap().join("tests/data/python/expressions/assign_renamed.py").sanitize();
+    FileMgr::rename_path(&mut session, &old_path, &new_path);
+
+    let file_mgr = session.sync_odoo.get_file_mgr();
+    let file_mgr = file_mgr.borrow();
+    assert!(!file_mgr.files.contains_key(&old_path), "the old path should no longer be tracked after rename");
+    let new_file_info = file_mgr.files.get(&new_path).expect("the new path should be tracked after rename");
+
+    // Same FileInfo instance, not a fresh reparse - this is the whole point of rename-in-place.
+    assert!(std::rc::Rc::ptr_eq(&old_file_info, new_file_info));
+    assert_eq!(new_file_info.borrow().uri, new_path);
+}